@@ -0,0 +1,33 @@
+// This example shows how to inspect bonding interfaces and their slaves.
+
+fn main() {
+    match netdev::net::bond::bonds() {
+        Ok(bonds) => {
+            for bond in bonds {
+                println!("[{}] {}", bond.index, bond.name);
+                println!("\tMode: {}", bond.mode);
+                println!("\tActive Slave: {:?}", bond.active_slave);
+                println!("\tMII Interval: {:?}", bond.miimon);
+                if let Some(agg) = bond.aggregator {
+                    println!(
+                        "\tAggregator: {} ({} ports, partner {})",
+                        agg.id, agg.num_ports, agg.partner_mac
+                    );
+                }
+                for slave in &bond.slaves {
+                    println!(
+                        "\tSlave [{}] {}: {:?} {:?}, {} link failures",
+                        slave.index,
+                        slave.name,
+                        slave.state,
+                        slave.mii_status,
+                        slave.link_failure_count
+                    );
+                }
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+        }
+    }
+}
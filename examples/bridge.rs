@@ -0,0 +1,60 @@
+// This example shows how to inspect bridges, their ports, and their forwarding databases.
+
+use netdev::net::bridge;
+
+fn main() {
+    match bridge::bridges() {
+        Ok(bridges) => {
+            for br in bridges {
+                println!("[{}] {}", br.index, br.name);
+                println!("\tSTP: {:?}", br.stp_state);
+                if let Some(id) = br.bridge_id {
+                    println!("\tBridge ID: {}", id);
+                }
+                if let Some(id) = br.root_id {
+                    println!("\tRoot ID: {} (root: {})", id, br.is_root());
+                }
+                println!("\tVLAN filtering: {}", br.vlan_filtering);
+                for port in &br.ports {
+                    println!(
+                        "\tPort [{}] {}: {} cost {:?} priority {:?}",
+                        port.index, port.name, port.state, port.cost, port.priority
+                    );
+                }
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+        }
+    }
+
+    match bridge::bridge_fdb() {
+        Ok(entries) => {
+            println!("FDB:");
+            for e in entries {
+                println!(
+                    "\t{} dev {:?} master {:?} vlan {:?} {:?}",
+                    e.mac_addr, e.if_name, e.master_index, e.vlan, e.state
+                );
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+        }
+    }
+
+    match bridge::bridge_mdb() {
+        Ok(entries) => {
+            println!("MDB:");
+            for e in entries {
+                println!(
+                    "\tbridge {} port {} group {:?} vlan {:?} permanent {}",
+                    e.bridge_index, e.port_index, e.group, e.vlan, e.permanent
+                );
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+        }
+    }
+}
@@ -40,6 +40,13 @@ fn main() {
             } else {
                 println!("Default Gateway: (Not found)");
             }
+            for gateway in &interface.gateways {
+                let mac_addr = gateway.mac_addr.map(|mac| mac.to_string());
+                println!(
+                    "\t{} (MAC: {:?}, Metric: {:?}, Table: {:?})",
+                    gateway.ip_addr, mac_addr, gateway.metric, gateway.table
+                );
+            }
             println!("DNS Servers: {:?}", interface.dns_servers);
             println!("MTU: {:?}", interface.mtu);
             println!("Default: {}", interface.default);
@@ -0,0 +1,20 @@
+// This example shows how to get the IPv4 and IPv6 default interfaces separately.
+
+fn main() {
+    let defaults = netdev::get_default_interfaces();
+    match &defaults.ipv4 {
+        Some(interface) => println!(
+            "IPv4 Default Interface: {} ({})",
+            interface.name, interface.index
+        ),
+        None => println!("IPv4 Default Interface: (Not found)"),
+    }
+    match &defaults.ipv6 {
+        Some(interface) => println!(
+            "IPv6 Default Interface: {} ({})",
+            interface.name, interface.index
+        ),
+        None => println!("IPv6 Default Interface: (Not found)"),
+    }
+    println!("Split: {}", defaults.is_split());
+}
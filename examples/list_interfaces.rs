@@ -9,6 +9,12 @@ fn main() {
         println!("\tFriendly Name: {:?}", interface.friendly_name);
         println!("\tDescription: {:?}", interface.description);
         println!("\tType: {}", interface.if_type.name());
+        if let Some(kind) = &interface.link_kind {
+            println!("\tKind: {}", kind);
+        }
+        if let Some(tunnel) = &interface.tunnel {
+            println!("\tTunnel: {:?}", tunnel);
+        }
         println!("\tFlags: {:?}", interface.flags);
         println!("\t\tis UP {}", interface.is_up());
         println!("\t\tis LOOPBACK {}", interface.is_loopback());
@@ -0,0 +1,14 @@
+// This example shows how to watch for network configuration changes.
+
+use std::ops::ControlFlow;
+
+fn main() {
+    println!("Watching for network changes. Press Ctrl+C to stop.");
+    let result = netdev::monitor::watch(|event| {
+        println!("{:?}", event);
+        ControlFlow::Continue(())
+    });
+    if let Err(e) = result {
+        println!("Error: {}", e);
+    }
+}
@@ -0,0 +1,25 @@
+// This example shows how to list the ARP and NDP neighbour cache entries.
+
+fn main() {
+    match netdev::net::neighbour::neighbours() {
+        Ok(neighbours) => {
+            for n in neighbours {
+                println!("{}", n.ip_addr);
+                if let Some(mac_addr) = n.mac_addr {
+                    println!("\tMAC Address: {}", mac_addr);
+                } else {
+                    println!("\tMAC Address: (unresolved)");
+                }
+                println!("\tInterface: {:?} ({})", n.if_name, n.if_index);
+                println!("\tState: {}", n.state);
+                println!("\tRouter: {}", n.is_router);
+                println!("\tConfirmed: {:?} ago", n.confirmed);
+                println!("\tUsed: {:?} ago", n.used);
+                println!("\tUpdated: {:?} ago", n.updated);
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+        }
+    }
+}
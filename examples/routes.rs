@@ -0,0 +1,29 @@
+// This example shows how to list the entries of the system routing tables.
+
+fn main() {
+    match netdev::route::get_routes() {
+        Ok(routes) => {
+            for route in routes {
+                println!("{}", route.destination);
+                println!("\tGateway: {:?}", route.gateway);
+                println!("\tInterface: {:?} ({:?})", route.if_name, route.if_index);
+                println!("\tMetric: {:?}", route.metric);
+                println!("\tTable: {}", route.table);
+                println!("\tProtocol: {}", route.protocol);
+                println!("\tScope: {}", route.scope);
+                println!("\tType: {}", route.route_type);
+                println!("\tPreferred Source: {:?}", route.pref_source);
+                println!("\tMTU: {:?}", route.mtu);
+                for nh in &route.nexthops {
+                    println!(
+                        "\tNext Hop: {:?} via {:?} ({}) weight {}",
+                        nh.gateway, nh.if_name, nh.if_index, nh.weight
+                    );
+                }
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+        }
+    }
+}
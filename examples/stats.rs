@@ -1,7 +1,7 @@
 use std::thread::sleep;
 use std::time::{Duration, SystemTime};
 
-use netdev::{self, Interface};
+use netdev::{self, Interface, StatsSampler};
 
 fn main() -> std::io::Result<()> {
     let mut iface = netdev::get_default_interface().expect("No default interface found");
@@ -13,6 +13,8 @@ fn main() -> std::io::Result<()> {
     // Initial stats
     println!("[Initial stats]");
     print_stats(&iface);
+    let mut sampler = StatsSampler::with_window(3);
+    sampler.sample_interface(&iface);
 
     // Update stats every second for 3 seconds
     for i in 1..=3 {
@@ -20,6 +22,24 @@ fn main() -> std::io::Result<()> {
         iface.update_stats()?;
         println!("\n[Update {}]", i);
         print_stats(&iface);
+        if let Some(rates) = sampler.sample_interface(&iface) {
+            println!(
+                "Rate: RX {:.0} bit/s, TX {:.0} bit/s",
+                rates.rx_bits_per_sec(),
+                rates.tx_bits_per_sec()
+            );
+            let utilization = rates.utilization(&iface);
+            if let Some(percent) = utilization.max_percent() {
+                println!("Utilization: {:.2}%", percent);
+            }
+        }
+    }
+
+    if let Some(summary) = sampler.summary(iface.index, |r| r.rx_bytes_per_sec) {
+        println!(
+            "\nRX bytes/s over {} samples: min {:.0}, avg {:.0}, max {:.0}",
+            summary.samples, summary.min, summary.avg, summary.max
+        );
     }
 
     Ok(())
@@ -34,6 +54,17 @@ fn print_stats(iface: &Interface) {
                 stats.tx_bytes,
                 stats.timestamp.unwrap_or(SystemTime::UNIX_EPOCH)
             );
+            if let Some(ext) = &stats.extended {
+                println!(
+                    "    {:>12} packets, {:>12} packets, errors {}/{}, dropped {}/{}",
+                    ext.rx_packets,
+                    ext.tx_packets,
+                    ext.rx_errors,
+                    ext.tx_errors,
+                    ext.rx_dropped,
+                    ext.tx_dropped
+                );
+            }
         }
         None => {
             println!("No statistics available for interface: {}", iface.name);
@@ -0,0 +1,24 @@
+// This example shows how to map veth interfaces to their peers, which usually live in
+// container network namespaces.
+
+fn main() {
+    match netdev::net::veth::veths() {
+        Ok(veths) => {
+            for veth in veths {
+                println!("[{}] {}", veth.index, veth.name);
+                println!(
+                    "\tPeer: {} ({})",
+                    veth.peer_name.as_deref().unwrap_or("(unknown)"),
+                    veth.peer_index
+                );
+                match veth.peer_netns_id {
+                    Some(id) => println!("\tPeer netns id: {}", id),
+                    None => println!("\tPeer netns: current"),
+                }
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+        }
+    }
+}
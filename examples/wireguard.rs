@@ -0,0 +1,44 @@
+// This example shows WireGuard interfaces and their peers, like `wg show`.
+// Reading the configuration requires CAP_NET_ADMIN.
+
+fn main() {
+    match netdev::net::wireguard::wireguard_devices() {
+        Ok(devices) => {
+            for device in devices {
+                println!("[{}] {}", device.index, device.name);
+                if let Some(key) = &device.public_key {
+                    println!("\tPublic key: {}", key);
+                }
+                if let Some(port) = device.listen_port {
+                    println!("\tListen port: {}", port);
+                }
+                if let Some(fwmark) = device.fwmark {
+                    println!("\tFwmark: {:#x}", fwmark);
+                }
+                for peer in &device.peers {
+                    println!("\tPeer: {}", peer.public_key);
+                    if let Some(endpoint) = peer.endpoint {
+                        println!("\t\tEndpoint: {}", endpoint);
+                    }
+                    println!("\t\tAllowed IPs: {:?}", peer.allowed_ips);
+                    match peer.latest_handshake.and_then(|t| t.elapsed().ok()) {
+                        Some(elapsed) => {
+                            println!("\t\tLatest handshake: {}s ago", elapsed.as_secs())
+                        }
+                        None => println!("\t\tLatest handshake: (none)"),
+                    }
+                    println!(
+                        "\t\tTransfer: {} B received, {} B sent",
+                        peer.rx_bytes, peer.tx_bytes
+                    );
+                    if let Some(keepalive) = peer.persistent_keepalive {
+                        println!("\t\tPersistent keepalive: {}s", keepalive.as_secs());
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+use std::io;
+
+/// Errors returned by `netdev`.
+///
+/// New variants may be added in minor releases, so matches must include a wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The netlink backend failed.
+    Netlink(io::Error),
+    /// The operating system denied access to the requested information.
+    PermissionDenied,
+    /// No default route was found.
+    NoDefaultRoute,
+    /// The local address used for outbound traffic could not be determined.
+    NoLocalAddress,
+    /// Data reported by the system or supplied by the caller could not be parsed.
+    Parse(String),
+    /// The operation is not supported on this platform.
+    Unsupported,
+    /// Another I/O error occurred.
+    Io(io::Error),
+}
+
+impl Error {
+    /// Wraps a failure of the netlink backend, keeping permission errors distinct.
+    #[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+    pub(crate) fn netlink(e: io::Error) -> Error {
+        match e.kind() {
+            io::ErrorKind::PermissionDenied => Error::PermissionDenied,
+            _ => Error::Netlink(e),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Netlink(e) => write!(f, "netlink error: {}", e),
+            Error::PermissionDenied => write!(f, "permission denied"),
+            Error::NoDefaultRoute => write!(f, "no default route found"),
+            Error::NoLocalAddress => write!(f, "local IP address not found"),
+            Error::Parse(msg) => write!(f, "parse error: {}", msg),
+            Error::Unsupported => write!(f, "not supported on this platform"),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Netlink(e) | Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        match e.kind() {
+            io::ErrorKind::PermissionDenied => Error::PermissionDenied,
+            io::ErrorKind::Unsupported => Error::Unsupported,
+            _ => Error::Io(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use std::io;
+
+    #[test]
+    fn classifies_io_errors() {
+        let denied = io::Error::from(io::ErrorKind::PermissionDenied);
+        assert!(matches!(Error::from(denied), Error::PermissionDenied));
+
+        let unsupported = io::Error::new(io::ErrorKind::Unsupported, "nope");
+        assert!(matches!(Error::from(unsupported), Error::Unsupported));
+
+        let other = io::Error::other("boom");
+        assert!(matches!(Error::netlink(other), Error::Netlink(_)));
+    }
+}
@@ -0,0 +1,439 @@
+use crate::interface::interface::Interface;
+use crate::interface::kind::LinkKind;
+use crate::interface::state::OperState;
+use crate::interface::tunnel::TunnelInfo;
+use crate::net::mac::MacAddr;
+use crate::stats::counters::InterfaceStats;
+use ipnet::IpNet;
+use std::collections::{BTreeMap, HashSet};
+
+#[cfg(feature = "gateway")]
+use crate::net::device::{Gateway, NetworkDevice};
+#[cfg(feature = "gateway")]
+use std::net::IpAddr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Controls which fields are compared by `diff_interfaces_with`.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DiffOptions {
+    /// Skip `Interface::stats`, which changes whenever traffic flows.
+    pub ignore_stats: bool,
+    /// Skip `Interface::transmit_speed` and `Interface::receive_speed`, which fluctuate on
+    /// wireless links.
+    pub ignore_link_speed: bool,
+}
+
+impl Default for DiffOptions {
+    /// Ignores traffic counters and link speeds.
+    fn default() -> Self {
+        DiffOptions {
+            ignore_stats: true,
+            ignore_link_speed: true,
+        }
+    }
+}
+
+/// A single difference between two snapshots of the same interface.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterfaceChange {
+    /// The system name changed.
+    Renamed {
+        /// Previous name.
+        old: String,
+        /// Current name.
+        new: String,
+    },
+    /// The link-layer address changed.
+    MacChanged {
+        /// Previous address.
+        old: Option<MacAddr>,
+        /// Current address.
+        new: Option<MacAddr>,
+    },
+    /// An address was assigned.
+    AddressAdded(IpNet),
+    /// An address was removed.
+    AddressRemoved(IpNet),
+    /// The raw interface flags changed.
+    FlagsChanged {
+        /// Previous flags.
+        old: u32,
+        /// Current flags.
+        new: u32,
+    },
+    /// The operational state changed.
+    OperStateChanged {
+        /// Previous state.
+        old: OperState,
+        /// Current state.
+        new: OperState,
+    },
+    /// The MTU changed.
+    MtuChanged {
+        /// Previous MTU.
+        old: Option<u32>,
+        /// Current MTU.
+        new: Option<u32>,
+    },
+    /// The driver kind or its details, such as the VLAN id, changed.
+    LinkKindChanged {
+        /// Previous kind.
+        old: Option<LinkKind>,
+        /// Current kind.
+        new: Option<LinkKind>,
+    },
+    /// The tunnel endpoints or parameters changed.
+    TunnelChanged {
+        /// Previous tunnel parameters.
+        old: Option<TunnelInfo>,
+        /// Current tunnel parameters.
+        new: Option<TunnelInfo>,
+    },
+    /// The transmit or receive link speed changed.
+    LinkSpeedChanged {
+        /// Previous transmit and receive speeds in bits per second.
+        old: (Option<u64>, Option<u64>),
+        /// Current transmit and receive speeds in bits per second.
+        new: (Option<u64>, Option<u64>),
+    },
+    /// The traffic counters changed.
+    StatsChanged {
+        /// Previous counters.
+        old: Option<Box<InterfaceStats>>,
+        /// Current counters.
+        new: Option<Box<InterfaceStats>>,
+    },
+    /// The default gateway changed.
+    ///
+    /// Only `Interface::gateway` is compared. Changes of route metrics, tables, or individual
+    /// routers are reported by `GatewaysChanged`.
+    ///
+    /// This variant is available only with the `gateway` feature.
+    #[cfg(feature = "gateway")]
+    GatewayChanged {
+        /// Previous gateway.
+        old: Option<NetworkDevice>,
+        /// Current gateway.
+        new: Option<NetworkDevice>,
+    },
+    /// The per-router default route entries changed, including their metrics, tables, and
+    /// link-layer addresses.
+    ///
+    /// This variant is available only with the `gateway` feature.
+    #[cfg(feature = "gateway")]
+    GatewaysChanged {
+        /// Previous entries.
+        old: Vec<Gateway>,
+        /// Current entries.
+        new: Vec<Gateway>,
+    },
+    /// The DNS resolver list changed.
+    ///
+    /// This variant is available only with the `gateway` feature.
+    #[cfg(feature = "gateway")]
+    DnsServersChanged {
+        /// Previous resolvers.
+        old: Vec<IpAddr>,
+        /// Current resolvers.
+        new: Vec<IpAddr>,
+    },
+}
+
+/// Differences between two interface enumerations, keyed by interface index.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InterfaceDiff {
+    /// Interfaces present only in the newer snapshot.
+    pub added: BTreeMap<u32, Interface>,
+    /// Interfaces present only in the older snapshot.
+    pub removed: BTreeMap<u32, Interface>,
+    /// Changes to interfaces present in both snapshots.
+    ///
+    /// Interfaces without changes are omitted.
+    pub changed: BTreeMap<u32, Vec<InterfaceChange>>,
+}
+
+impl InterfaceDiff {
+    /// Returns `true` when the two snapshots are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares two interface snapshots, ignoring traffic counters and link speeds.
+///
+/// Interfaces are matched by `Interface::index`. See `diff_interfaces_with` to choose which
+/// fields are compared.
+pub fn diff_interfaces(old: &[Interface], new: &[Interface]) -> InterfaceDiff {
+    diff_interfaces_with(old, new, &DiffOptions::default())
+}
+
+/// Compares two interface snapshots with the given options.
+///
+/// Interfaces are matched by `Interface::index`. Address lists are compared as sets, so
+/// reordering alone is not reported.
+pub fn diff_interfaces_with(
+    old: &[Interface],
+    new: &[Interface],
+    options: &DiffOptions,
+) -> InterfaceDiff {
+    let old_map: BTreeMap<u32, &Interface> = old.iter().map(|i| (i.index, i)).collect();
+    let new_map: BTreeMap<u32, &Interface> = new.iter().map(|i| (i.index, i)).collect();
+
+    let mut diff = InterfaceDiff::default();
+    for (index, old_iface) in &old_map {
+        match new_map.get(index) {
+            Some(new_iface) => {
+                let changes = diff_interface(old_iface, new_iface, options);
+                if !changes.is_empty() {
+                    diff.changed.insert(*index, changes);
+                }
+            }
+            None => {
+                diff.removed.insert(*index, (*old_iface).clone());
+            }
+        }
+    }
+    for (index, new_iface) in &new_map {
+        if !old_map.contains_key(index) {
+            diff.added.insert(*index, (*new_iface).clone());
+        }
+    }
+    diff
+}
+
+fn addresses(iface: &Interface) -> Vec<IpNet> {
+    iface
+        .ipv4
+        .iter()
+        .map(|n| IpNet::V4(*n))
+        .chain(iface.ipv6.iter().map(|n| IpNet::V6(*n)))
+        .collect()
+}
+
+fn diff_interface(old: &Interface, new: &Interface, options: &DiffOptions) -> Vec<InterfaceChange> {
+    let mut changes = Vec::new();
+
+    if old.name != new.name {
+        changes.push(InterfaceChange::Renamed {
+            old: old.name.clone(),
+            new: new.name.clone(),
+        });
+    }
+    if old.mac_addr != new.mac_addr {
+        changes.push(InterfaceChange::MacChanged {
+            old: old.mac_addr,
+            new: new.mac_addr,
+        });
+    }
+
+    let old_addrs = addresses(old);
+    let new_addrs = addresses(new);
+    let old_set: HashSet<&IpNet> = old_addrs.iter().collect();
+    let new_set: HashSet<&IpNet> = new_addrs.iter().collect();
+    for addr in &new_addrs {
+        if !old_set.contains(addr) {
+            changes.push(InterfaceChange::AddressAdded(*addr));
+        }
+    }
+    for addr in &old_addrs {
+        if !new_set.contains(addr) {
+            changes.push(InterfaceChange::AddressRemoved(*addr));
+        }
+    }
+
+    if old.flags != new.flags {
+        changes.push(InterfaceChange::FlagsChanged {
+            old: old.flags,
+            new: new.flags,
+        });
+    }
+    if old.oper_state != new.oper_state {
+        changes.push(InterfaceChange::OperStateChanged {
+            old: old.oper_state,
+            new: new.oper_state,
+        });
+    }
+    if old.mtu != new.mtu {
+        changes.push(InterfaceChange::MtuChanged {
+            old: old.mtu,
+            new: new.mtu,
+        });
+    }
+    if old.link_kind != new.link_kind {
+        changes.push(InterfaceChange::LinkKindChanged {
+            old: old.link_kind.clone(),
+            new: new.link_kind.clone(),
+        });
+    }
+    if old.tunnel != new.tunnel {
+        changes.push(InterfaceChange::TunnelChanged {
+            old: old.tunnel,
+            new: new.tunnel,
+        });
+    }
+    let old_speed = (old.transmit_speed, old.receive_speed);
+    let new_speed = (new.transmit_speed, new.receive_speed);
+    if !options.ignore_link_speed && old_speed != new_speed {
+        changes.push(InterfaceChange::LinkSpeedChanged {
+            old: old_speed,
+            new: new_speed,
+        });
+    }
+    if !options.ignore_stats && old.stats != new.stats {
+        changes.push(InterfaceChange::StatsChanged {
+            old: old.stats.clone().map(Box::new),
+            new: new.stats.clone().map(Box::new),
+        });
+    }
+
+    #[cfg(feature = "gateway")]
+    {
+        if old.gateway != new.gateway {
+            changes.push(InterfaceChange::GatewayChanged {
+                old: old.gateway.clone(),
+                new: new.gateway.clone(),
+            });
+        }
+        if old.gateways != new.gateways {
+            changes.push(InterfaceChange::GatewaysChanged {
+                old: old.gateways.clone(),
+                new: new.gateways.clone(),
+            });
+        }
+        if old.dns_servers != new.dns_servers {
+            changes.push(InterfaceChange::DnsServersChanged {
+                old: old.dns_servers.clone(),
+                new: new.dns_servers.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffOptions, InterfaceChange, diff_interfaces, diff_interfaces_with};
+    use crate::interface::interface::Interface;
+    use crate::interface::state::OperState;
+    use crate::stats::counters::{CounterWidth, InterfaceStats};
+    use ipnet::Ipv4Net;
+    use std::net::Ipv4Addr;
+
+    fn iface(index: u32, name: &str) -> Interface {
+        let mut iface = Interface::dummy();
+        iface.index = index;
+        iface.name = name.to_string();
+        iface.ipv4 = vec![Ipv4Net::new(Ipv4Addr::new(10, 0, 0, index as u8), 24).unwrap()];
+        iface
+    }
+
+    #[test]
+    fn reports_added_and_removed_interfaces() {
+        let old = vec![iface(1, "lo"), iface(2, "eth0")];
+        let new = vec![iface(1, "lo"), iface(3, "wg0")];
+
+        let diff = diff_interfaces(&old, &new);
+
+        assert_eq!(diff.added.keys().copied().collect::<Vec<_>>(), vec![3]);
+        assert_eq!(diff.removed.keys().copied().collect::<Vec<_>>(), vec![2]);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn reports_field_changes_by_index() {
+        let old = vec![iface(2, "eth0")];
+        let mut changed = iface(2, "lan0");
+        changed.ipv4 = vec![Ipv4Net::new(Ipv4Addr::new(192, 168, 1, 2), 24).unwrap()];
+        changed.oper_state = OperState::Up;
+        changed.mtu = Some(9000);
+
+        let diff = diff_interfaces(&old, &[changed]);
+
+        assert_eq!(
+            diff.changed[&2],
+            vec![
+                InterfaceChange::Renamed {
+                    old: "eth0".into(),
+                    new: "lan0".into(),
+                },
+                InterfaceChange::AddressAdded("192.168.1.2/24".parse().unwrap()),
+                InterfaceChange::AddressRemoved("10.0.0.2/24".parse().unwrap()),
+                InterfaceChange::OperStateChanged {
+                    old: OperState::Unknown,
+                    new: OperState::Up,
+                },
+                InterfaceChange::MtuChanged {
+                    old: None,
+                    new: Some(9000),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_volatile_fields_by_default() {
+        let old = vec![iface(2, "eth0")];
+        let mut new = iface(2, "eth0");
+        new.stats = Some(InterfaceStats {
+            rx_bytes: 10,
+            tx_bytes: 20,
+            timestamp: None,
+            counter_width: CounterWidth::Bits64,
+            extended: None,
+        });
+        new.transmit_speed = Some(1_000_000_000);
+        let new = vec![new];
+
+        assert!(diff_interfaces(&old, &new).is_empty());
+
+        let options = DiffOptions {
+            ignore_stats: false,
+            ignore_link_speed: false,
+        };
+        let diff = diff_interfaces_with(&old, &new, &options);
+        assert_eq!(diff.changed[&2].len(), 2);
+    }
+
+    #[cfg(feature = "gateway")]
+    #[test]
+    fn reports_gateway_metric_changes() {
+        use crate::net::device::Gateway;
+
+        let gateway = |metric| Gateway {
+            metric: Some(metric),
+            table: Some(254),
+            ..Gateway::new(Ipv4Addr::new(10, 0, 0, 1).into())
+        };
+        let mut old = iface(2, "eth0");
+        old.gateways = vec![gateway(100)];
+        let mut new = old.clone();
+        new.gateways = vec![gateway(600)];
+
+        let diff = diff_interfaces(&[old], &[new]);
+
+        assert_eq!(
+            diff.changed[&2],
+            vec![InterfaceChange::GatewaysChanged {
+                old: vec![gateway(100)],
+                new: vec![gateway(600)],
+            }]
+        );
+    }
+
+    #[test]
+    fn address_order_is_not_a_change() {
+        let mut old = iface(2, "eth0");
+        old.ipv4
+            .push(Ipv4Net::new(Ipv4Addr::new(10, 0, 1, 2), 24).unwrap());
+        let mut new = old.clone();
+        new.ipv4.reverse();
+
+        assert!(diff_interfaces(&[old], &[new]).is_empty());
+    }
+}
@@ -0,0 +1,329 @@
+use crate::error::Error;
+use crate::interface::interface::Interface;
+use crate::interface::types::InterfaceType;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Type names accepted by the `type=` selector key.
+const TYPE_NAMES: &[(&str, InterfaceType)] = &[
+    ("ethernet", InterfaceType::Ethernet),
+    ("wifi", InterfaceType::Wireless80211),
+    ("wireless", InterfaceType::Wireless80211),
+    ("loopback", InterfaceType::Loopback),
+    ("tunnel", InterfaceType::Tunnel),
+    ("bridge", InterfaceType::Bridge),
+    ("ppp", InterfaceType::Ppp),
+    ("slip", InterfaceType::Slip),
+    ("wwan", InterfaceType::Wwan),
+    ("can", InterfaceType::Can),
+    ("p2p", InterfaceType::PeerToPeerWireless),
+    ("virtual", InterfaceType::ProprietaryVirtual),
+    ("unknown", InterfaceType::Unknown),
+];
+
+/// A reusable set of conditions that an `Interface` must satisfy.
+///
+/// Every condition that is set must hold. Conditions that accept several values, such as
+/// `InterfaceFilter::if_type` and `InterfaceFilter::name`, match when any of their values
+/// matches.
+///
+/// Filters can also be parsed from a comma-separated selector string, which is convenient for
+/// configuration files and command-line flags:
+///
+/// ```
+/// use netdev::InterfaceFilter;
+///
+/// let filter: InterfaceFilter = "type=ethernet|wifi,up,!loopback,name=en*".parse().unwrap();
+/// let selected: Vec<_> = netdev::get_interfaces()
+///     .into_iter()
+///     .filter(|iface| filter.matches(iface))
+///     .collect();
+/// # let _ = selected;
+/// ```
+///
+/// The selector syntax accepts these terms:
+///
+/// - `up`, `running`, `physical`, `loopback`, `global-ipv4`, and `global-ipv6`, each
+///   optionally negated with a leading `!`.
+/// - `type=<name>`, where the name is one of `ethernet`, `wifi` (or `wireless`), `loopback`,
+///   `tunnel`, `bridge`, `ppp`, `slip`, `wwan`, `can`, `p2p`, `virtual`, or `unknown`.
+/// - `name=<glob>`, where `*` matches any run of characters and `?` matches one character.
+/// - `oui=<xx:xx:xx>`, matching the first three octets of the MAC address. `-` separators
+///   and bare hex digits are also accepted.
+///
+/// Values of `type`, `name`, and `oui` can be separated by `|`, and repeated keys add
+/// alternatives.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InterfaceFilter {
+    up: Option<bool>,
+    running: Option<bool>,
+    physical: Option<bool>,
+    loopback: Option<bool>,
+    global_ipv4: Option<bool>,
+    global_ipv6: Option<bool>,
+    if_types: Vec<InterfaceType>,
+    names: Vec<String>,
+    ouis: Vec<[u8; 3]>,
+}
+
+impl InterfaceFilter {
+    /// Creates a filter that matches every interface.
+    pub fn new() -> InterfaceFilter {
+        InterfaceFilter::default()
+    }
+    /// Requires `Interface::is_up` to equal `up`.
+    pub fn up(mut self, up: bool) -> InterfaceFilter {
+        self.up = Some(up);
+        self
+    }
+    /// Requires `Interface::is_running` to equal `running`.
+    pub fn running(mut self, running: bool) -> InterfaceFilter {
+        self.running = Some(running);
+        self
+    }
+    /// Requires `Interface::is_physical` to equal `physical`.
+    pub fn physical(mut self, physical: bool) -> InterfaceFilter {
+        self.physical = Some(physical);
+        self
+    }
+    /// Requires `Interface::is_loopback` to equal `loopback`.
+    pub fn loopback(mut self, loopback: bool) -> InterfaceFilter {
+        self.loopback = Some(loopback);
+        self
+    }
+    /// Requires `Interface::has_global_ipv4` to equal `global`.
+    pub fn global_ipv4(mut self, global: bool) -> InterfaceFilter {
+        self.global_ipv4 = Some(global);
+        self
+    }
+    /// Requires `Interface::has_global_ipv6` to equal `global`.
+    pub fn global_ipv6(mut self, global: bool) -> InterfaceFilter {
+        self.global_ipv6 = Some(global);
+        self
+    }
+    /// Accepts interfaces of type `if_type`, in addition to previously accepted types.
+    pub fn if_type(mut self, if_type: InterfaceType) -> InterfaceFilter {
+        self.if_types.push(if_type);
+        self
+    }
+    /// Accepts interfaces whose name matches the glob `pattern`, in addition to previously
+    /// accepted patterns.
+    pub fn name(mut self, pattern: impl Into<String>) -> InterfaceFilter {
+        self.names.push(pattern.into());
+        self
+    }
+    /// Accepts interfaces whose MAC address starts with `oui`, in addition to previously
+    /// accepted prefixes.
+    pub fn oui(mut self, oui: [u8; 3]) -> InterfaceFilter {
+        self.ouis.push(oui);
+        self
+    }
+    /// Returns `true` when `iface` satisfies every condition of this filter.
+    pub fn matches(&self, iface: &Interface) -> bool {
+        let flag = |wanted: Option<bool>, actual: fn(&Interface) -> bool| {
+            wanted.is_none_or(|wanted| actual(iface) == wanted)
+        };
+
+        flag(self.up, Interface::is_up)
+            && flag(self.running, Interface::is_running)
+            && flag(self.loopback, Interface::is_loopback)
+            && flag(self.global_ipv4, Interface::has_global_ipv4)
+            && flag(self.global_ipv6, Interface::has_global_ipv6)
+            && (self.if_types.is_empty() || self.if_types.contains(&iface.if_type))
+            && (self.names.is_empty() || self.names.iter().any(|p| glob_match(p, &iface.name)))
+            && (self.ouis.is_empty()
+                || iface
+                    .mac_addr
+                    .is_some_and(|mac| self.ouis.contains(&mac.oui())))
+            // Checked last because it consults the OUI database.
+            && flag(self.physical, Interface::is_physical)
+    }
+    /// Keeps only the interfaces of `ifaces` that match this filter.
+    pub fn apply(&self, mut ifaces: Vec<Interface>) -> Vec<Interface> {
+        ifaces.retain(|iface| self.matches(iface));
+        ifaces
+    }
+}
+
+impl FromStr for InterfaceFilter {
+    type Err = Error;
+
+    /// Parses a selector such as `type=ethernet,up,name=eth*`.
+    ///
+    /// Returns `Error::Parse` for unknown terms, type names, or malformed OUIs.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = InterfaceFilter::new();
+        for term in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            if let Some((key, values)) = term.split_once('=') {
+                let values = values.split('|').map(str::trim);
+                match key.trim().to_ascii_lowercase().as_str() {
+                    "type" => {
+                        for value in values {
+                            filter.if_types.push(parse_type(value)?);
+                        }
+                    }
+                    "name" => filter.names.extend(values.map(String::from)),
+                    "oui" => {
+                        for value in values {
+                            filter.ouis.push(parse_oui(value)?);
+                        }
+                    }
+                    other => return Err(Error::Parse(format!("unknown selector key `{}`", other))),
+                }
+                continue;
+            }
+
+            let (wanted, flag) = match term.strip_prefix('!') {
+                Some(flag) => (false, flag.trim()),
+                None => (true, term),
+            };
+            let slot = match flag.to_ascii_lowercase().replace('_', "-").as_str() {
+                "up" => &mut filter.up,
+                "running" => &mut filter.running,
+                "physical" => &mut filter.physical,
+                "loopback" => &mut filter.loopback,
+                "global-ipv4" => &mut filter.global_ipv4,
+                "global-ipv6" => &mut filter.global_ipv6,
+                _ => return Err(Error::Parse(format!("unknown selector `{}`", term))),
+            };
+            *slot = Some(wanted);
+        }
+        Ok(filter)
+    }
+}
+
+fn parse_type(name: &str) -> Result<InterfaceType, Error> {
+    TYPE_NAMES
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, ty)| *ty)
+        .ok_or_else(|| Error::Parse(format!("unknown interface type `{}`", name)))
+}
+
+fn parse_oui(s: &str) -> Result<[u8; 3], Error> {
+    let hex: String = s.chars().filter(|c| *c != ':' && *c != '-').collect();
+    let invalid = || Error::Parse(format!("invalid OUI `{}`", s));
+    if hex.len() != 6 || !hex.is_ascii() {
+        return Err(invalid());
+    }
+    let mut oui = [0u8; 3];
+    for (i, byte) in oui.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(oui)
+}
+
+/// Matches `name` against a glob where `*` matches any run of characters and `?` matches one.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` in the pattern and the name position it was tried at.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, tried)) => {
+                    p = star + 1;
+                    n = tried + 1;
+                    backtrack = Some((star, tried + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InterfaceFilter, glob_match};
+    use crate::error::Error;
+    use crate::interface::interface::Interface;
+    use crate::interface::types::InterfaceType;
+    use crate::net::mac::MacAddr;
+
+    fn iface(name: &str, if_type: InterfaceType, flags: u32) -> Interface {
+        let mut iface = Interface::dummy();
+        iface.name = name.to_string();
+        iface.if_type = if_type;
+        iface.flags = flags;
+        iface.mac_addr = Some(MacAddr::new(0x00, 0x1b, 0x21, 0x01, 0x02, 0x03));
+        iface
+    }
+
+    #[test]
+    fn globs_match_names() {
+        assert!(glob_match("eth*", "eth0"));
+        assert!(glob_match("enp?s*", "enp3s0"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*0", "wlan0"));
+        assert!(!glob_match("eth*", "wlan0"));
+        assert!(!glob_match("eth?", "eth10"));
+    }
+
+    #[test]
+    fn parses_selector_strings() {
+        let filter: InterfaceFilter = "type=ethernet|wifi, up, !loopback, name=en*, oui=00-1B-21"
+            .parse()
+            .unwrap();
+        let expected = InterfaceFilter::new()
+            .if_type(InterfaceType::Ethernet)
+            .if_type(InterfaceType::Wireless80211)
+            .up(true)
+            .loopback(false)
+            .name("en*")
+            .oui([0x00, 0x1b, 0x21]);
+        assert_eq!(filter, expected);
+
+        assert!(matches!(
+            "type=token".parse::<InterfaceFilter>(),
+            Err(Error::Parse(_))
+        ));
+        assert!(matches!(
+            "fast".parse::<InterfaceFilter>(),
+            Err(Error::Parse(_))
+        ));
+        assert!(matches!(
+            "oui=00:1b".parse::<InterfaceFilter>(),
+            Err(Error::Parse(_))
+        ));
+        assert_eq!(
+            "".parse::<InterfaceFilter>().unwrap(),
+            InterfaceFilter::new()
+        );
+    }
+
+    #[test]
+    fn matches_every_condition() {
+        let up = crate::interface::flags::IFF_UP as u32;
+        let eth = iface("eth0", InterfaceType::Ethernet, up);
+        let wlan = iface("wlan0", InterfaceType::Wireless80211, up);
+        let down = iface("eth1", InterfaceType::Ethernet, 0);
+
+        let filter: InterfaceFilter = "type=ethernet,up,name=eth*".parse().unwrap();
+        assert!(filter.matches(&eth));
+        assert!(!filter.matches(&wlan));
+        assert!(!filter.matches(&down));
+
+        let by_oui = InterfaceFilter::new().oui([0x00, 0x1b, 0x21]);
+        assert_eq!(by_oui.apply(vec![eth, wlan, down]).len(), 3);
+        assert!(
+            !InterfaceFilter::new()
+                .oui([0x02, 0, 0])
+                .matches(&Interface::dummy())
+        );
+    }
+}
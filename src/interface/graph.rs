@@ -0,0 +1,285 @@
+use crate::interface::kind::LinkKind;
+use std::collections::{BTreeSet, HashSet};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An interface in an `InterfaceGraph`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GraphNode {
+    /// Interface index.
+    pub index: u32,
+    /// Interface name.
+    pub name: String,
+    /// Driver kind, `None` for physical NICs and loopback.
+    pub kind: Option<LinkKind>,
+    /// `true` when the interface is backed by a hardware device.
+    pub is_physical: bool,
+}
+
+/// How the upper interface of an edge depends on the lower one.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Relation {
+    /// The lower interface is a port of the upper one, such as a bond slave or a bridge port.
+    Master,
+    /// The upper interface is built on top of the lower one, such as a VLAN on its parent.
+    Stacked,
+}
+
+/// A dependency between two interfaces.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GraphEdge {
+    /// Index of the dependent interface.
+    pub upper: u32,
+    /// Index of the interface it depends on.
+    pub lower: u32,
+    /// Kind of dependency.
+    pub relation: Relation,
+}
+
+/// Dependencies between the interfaces of a host.
+///
+/// An edge points from an upper interface to a lower one. For example `bond0.100` is stacked
+/// on `bond0`, which is the master of `eth0` and `eth1`, so following lower edges from
+/// `bond0.100` leads to the physical uplinks.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InterfaceGraph {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+}
+
+impl InterfaceGraph {
+    /// Builds a graph, sorting nodes by index.
+    ///
+    /// Edges to unknown interfaces and self-loops are dropped. When two edges connect the same
+    /// pair of interfaces, a `Relation::Master` edge wins.
+    pub(crate) fn new(mut nodes: Vec<GraphNode>, edges: Vec<GraphEdge>) -> InterfaceGraph {
+        nodes.sort_by_key(|node| node.index);
+        nodes.dedup_by_key(|node| node.index);
+
+        let known: HashSet<u32> = nodes.iter().map(|node| node.index).collect();
+        let mut edges: Vec<GraphEdge> = edges
+            .into_iter()
+            .filter(|e| e.upper != e.lower && known.contains(&e.upper) && known.contains(&e.lower))
+            .collect();
+        edges.sort_by_key(|e| (e.upper, e.lower, e.relation != Relation::Master));
+        edges.dedup_by_key(|e| (e.upper, e.lower));
+
+        InterfaceGraph { nodes, edges }
+    }
+    /// Returns every interface, ordered by index.
+    pub fn nodes(&self) -> &[GraphNode] {
+        &self.nodes
+    }
+    /// Returns every dependency, ordered by upper and then lower index.
+    pub fn edges(&self) -> &[GraphEdge] {
+        &self.edges
+    }
+    /// Returns the interface with the given index.
+    pub fn node(&self, index: u32) -> Option<&GraphNode> {
+        self.nodes
+            .binary_search_by_key(&index, |node| node.index)
+            .ok()
+            .map(|pos| &self.nodes[pos])
+    }
+    /// Returns the interface with the given name.
+    pub fn node_by_name(&self, name: &str) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.name == name)
+    }
+    /// Returns the interfaces that `index` directly depends on.
+    pub fn lowers(&self, index: u32) -> Vec<&GraphNode> {
+        self.collect(
+            self.edges
+                .iter()
+                .filter(|e| e.upper == index)
+                .map(|e| e.lower),
+        )
+    }
+    /// Returns the interfaces that directly depend on `index`.
+    pub fn uppers(&self, index: u32) -> Vec<&GraphNode> {
+        self.collect(
+            self.edges
+                .iter()
+                .filter(|e| e.lower == index)
+                .map(|e| e.upper),
+        )
+    }
+    /// Returns the bond, bridge, or VRF that `index` is enslaved to.
+    pub fn master(&self, index: u32) -> Option<&GraphNode> {
+        self.edges
+            .iter()
+            .find(|e| e.lower == index && e.relation == Relation::Master)
+            .and_then(|e| self.node(e.upper))
+    }
+    /// Returns the ports enslaved to the bond, bridge, or VRF `index`.
+    pub fn ports(&self, index: u32) -> Vec<&GraphNode> {
+        self.collect(
+            self.edges
+                .iter()
+                .filter(|e| e.upper == index && e.relation == Relation::Master)
+                .map(|e| e.lower),
+        )
+    }
+    /// Returns the physical interfaces that traffic of `index` ultimately leaves through.
+    ///
+    /// A physical interface is its own uplink. The result is empty for purely virtual devices,
+    /// such as a dummy or a bridge without physical ports.
+    pub fn physical_uplinks(&self, index: u32) -> Vec<&GraphNode> {
+        let mut found = BTreeSet::new();
+        self.walk(
+            index,
+            |e| e.upper,
+            |e| e.lower,
+            |node| {
+                if node.is_physical {
+                    found.insert(node.index);
+                    // Do not descend below a physical interface.
+                    return false;
+                }
+                true
+            },
+        );
+        self.collect(found.into_iter())
+    }
+    /// Returns every interface stacked directly or transitively above `index`.
+    pub fn stacked_above(&self, index: u32) -> Vec<&GraphNode> {
+        let mut found = BTreeSet::new();
+        self.walk(
+            index,
+            |e| e.lower,
+            |e| e.upper,
+            |node| {
+                if node.index != index {
+                    found.insert(node.index);
+                }
+                true
+            },
+        );
+        self.collect(found.into_iter())
+    }
+
+    /// Visits `start` and the nodes reachable from it, following edges whose `from` end
+    /// matches the current node. `visit` returns `false` to stop at a node.
+    fn walk(
+        &self,
+        start: u32,
+        from: impl Fn(&GraphEdge) -> u32,
+        to: impl Fn(&GraphEdge) -> u32,
+        mut visit: impl FnMut(&GraphNode) -> bool,
+    ) {
+        let mut seen = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(index) = stack.pop() {
+            if !seen.insert(index) {
+                continue;
+            }
+            let Some(node) = self.node(index) else {
+                continue;
+            };
+            if visit(node) {
+                stack.extend(self.edges.iter().filter(|e| from(e) == index).map(&to));
+            }
+        }
+    }
+
+    fn collect(&self, indexes: impl Iterator<Item = u32>) -> Vec<&GraphNode> {
+        let indexes: BTreeSet<u32> = indexes.collect();
+        indexes.into_iter().filter_map(|i| self.node(i)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GraphEdge, GraphNode, InterfaceGraph, Relation};
+    use crate::interface::kind::{LinkKind, VlanInfo, VlanProtocol};
+
+    fn node(index: u32, name: &str, kind: Option<LinkKind>) -> GraphNode {
+        GraphNode {
+            index,
+            name: name.into(),
+            is_physical: kind.is_none() && name != "lo",
+            kind,
+        }
+    }
+
+    fn edge(upper: u32, lower: u32, relation: Relation) -> GraphEdge {
+        GraphEdge {
+            upper,
+            lower,
+            relation,
+        }
+    }
+
+    fn names(nodes: Vec<&GraphNode>) -> Vec<&str> {
+        nodes.iter().map(|n| n.name.as_str()).collect()
+    }
+
+    fn sample() -> InterfaceGraph {
+        let vlan = LinkKind::Vlan(VlanInfo {
+            id: 100,
+            protocol: VlanProtocol::Ieee8021Q,
+        });
+        InterfaceGraph::new(
+            vec![
+                node(1, "lo", None),
+                node(2, "eth0", None),
+                node(3, "eth1", None),
+                node(4, "bond0", Some(LinkKind::Bond)),
+                node(5, "bond0.100", Some(vlan)),
+                node(6, "br0", Some(LinkKind::Bridge)),
+                node(7, "veth123", Some(LinkKind::Veth)),
+            ],
+            vec![
+                edge(4, 2, Relation::Master),
+                edge(4, 3, Relation::Master),
+                edge(5, 4, Relation::Stacked),
+                edge(6, 5, Relation::Master),
+                edge(6, 7, Relation::Master),
+                // Reported again by the sysfs adjacency links.
+                edge(4, 2, Relation::Stacked),
+                edge(6, 6, Relation::Stacked),
+                edge(6, 42, Relation::Stacked),
+            ],
+        )
+    }
+
+    #[test]
+    fn dedupes_edges() {
+        let graph = sample();
+        assert_eq!(graph.edges().len(), 5);
+        assert_eq!(graph.edges()[0], edge(4, 2, Relation::Master));
+    }
+
+    #[test]
+    fn resolves_direct_relations() {
+        let graph = sample();
+        assert_eq!(names(graph.ports(4)), vec!["eth0", "eth1"]);
+        assert_eq!(names(graph.ports(6)), vec!["bond0.100", "veth123"]);
+        assert_eq!(graph.master(7).map(|n| n.name.as_str()), Some("br0"));
+        assert_eq!(graph.master(5).map(|n| n.name.as_str()), Some("br0"));
+        assert!(graph.master(4).is_none());
+        assert_eq!(names(graph.lowers(5)), vec!["bond0"]);
+        assert_eq!(names(graph.uppers(4)), vec!["bond0.100"]);
+        assert_eq!(graph.node_by_name("bond0").map(|n| n.index), Some(4));
+    }
+
+    #[test]
+    fn walks_the_stack() {
+        let graph = sample();
+        assert_eq!(names(graph.physical_uplinks(5)), vec!["eth0", "eth1"]);
+        assert_eq!(names(graph.physical_uplinks(6)), vec!["eth0", "eth1"]);
+        assert_eq!(names(graph.physical_uplinks(2)), vec!["eth0"]);
+        assert!(graph.physical_uplinks(7).is_empty());
+        assert!(graph.physical_uplinks(1).is_empty());
+
+        assert_eq!(
+            names(graph.stacked_above(2)),
+            vec!["bond0", "bond0.100", "br0"]
+        );
+        assert!(graph.stacked_above(6).is_empty());
+    }
+}
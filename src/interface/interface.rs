@@ -1,5 +1,7 @@
 use crate::interface::ipv6_addr_flags::Ipv6AddrFlags;
+use crate::interface::kind::LinkKind;
 use crate::interface::state::OperState;
+use crate::interface::tunnel::TunnelInfo;
 use crate::ipnet::{Ipv4Net, Ipv6Net};
 use crate::net::ip::{is_global_ip, is_global_ipv4, is_global_ipv6};
 use crate::stats::counters::InterfaceStats;
@@ -7,7 +9,7 @@ use crate::{interface::types::InterfaceType, net::mac::MacAddr};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 #[cfg(feature = "gateway")]
-use crate::net::device::NetworkDevice;
+use crate::net::device::{Gateway, NetworkDevice};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -42,6 +44,16 @@ pub struct Interface {
     /// The value is derived from platform-specific type identifiers and may be
     /// `InterfaceType::Unknown` when the OS does not expose a recognizable type.
     pub if_type: InterfaceType,
+    /// Driver kind of a virtual interface, such as a bridge, VLAN, or VXLAN.
+    ///
+    /// This is collected on Linux from `IFLA_LINKINFO`, and is `None` for physical devices,
+    /// loopback, and on other platforms.
+    pub link_kind: Option<LinkKind>,
+    /// Endpoints and parameters of a tunnel interface.
+    ///
+    /// This is collected on Linux from `IFLA_INFO_DATA` for VXLAN, Geneve, GRE, IPIP, SIT, and
+    /// ip6tnl interfaces, and is `None` for other interfaces and on other platforms.
+    pub tunnel: Option<TunnelInfo>,
     /// Link-layer address of the interface, when available.
     ///
     /// This field may be `None` for interfaces without a MAC address, for virtual interfaces,
@@ -110,6 +122,15 @@ pub struct Interface {
     /// resolved, the device contains a zero MAC address.
     #[cfg(feature = "gateway")]
     pub gateway: Option<NetworkDevice>,
+    /// Default gateways reachable through this interface, one entry per router address.
+    ///
+    /// Entries are ordered by route metric, lowest first. On Linux each entry carries the
+    /// metric, table, and link-layer address of its own router. Other platforms derive the
+    /// entries from `Interface::gateway` without metrics.
+    ///
+    /// This field is available only with the `gateway` feature.
+    #[cfg(feature = "gateway")]
+    pub gateways: Vec<Gateway>,
     /// DNS resolver addresses associated with this interface.
     ///
     /// This field is available only with the `gateway` feature.
@@ -121,15 +142,53 @@ pub struct Interface {
     pub mtu: Option<u32>,
     /// Whether this interface was identified as the default route.
     ///
+    /// IPv4 is preferred: on dual-stack hosts this follows `Interface::default_v4`, and it
+    /// follows `Interface::default_v6` only when there is no IPv4 default route.
+    ///
     /// This field is available only with the `gateway` feature.
     #[cfg(feature = "gateway")]
     pub default: bool,
+    /// Whether this interface carries the IPv4 default route.
+    ///
+    /// This field is available only with the `gateway` feature.
+    #[cfg(feature = "gateway")]
+    pub default_v4: bool,
+    /// Whether this interface carries the IPv6 default route.
+    ///
+    /// This may differ from `Interface::default_v4`, for example with native IPv6 and IPv4
+    /// over a tunnel. This field is available only with the `gateway` feature.
+    #[cfg(feature = "gateway")]
+    pub default_v6: bool,
+}
+
+/// Default interfaces of each address family.
+///
+/// This type is available only with the `gateway` feature.
+#[cfg(feature = "gateway")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DefaultInterfaces {
+    /// Interface carrying the IPv4 default route, when there is one.
+    pub ipv4: Option<Interface>,
+    /// Interface carrying the IPv6 default route, when there is one.
+    pub ipv6: Option<Interface>,
+}
+
+#[cfg(feature = "gateway")]
+impl DefaultInterfaces {
+    /// Returns `true` when IPv4 and IPv6 default routes leave through different interfaces.
+    pub fn is_split(&self) -> bool {
+        match (&self.ipv4, &self.ipv6) {
+            (Some(v4), Some(v6)) => v4.index != v6.index,
+            _ => false,
+        }
+    }
 }
 
 impl Interface {
     /// Returns the interface currently selected as the system default route.
     #[cfg(feature = "gateway")]
-    pub fn default() -> Result<Interface, String> {
+    pub fn default() -> Result<Interface, crate::error::Error> {
         super::resolve_default_interface(super::interfaces())
     }
     /// Returns an empty placeholder interface.
@@ -142,6 +201,8 @@ impl Interface {
             friendly_name: None,
             description: None,
             if_type: InterfaceType::Unknown,
+            link_kind: None,
+            tunnel: None,
             mac_addr: None,
             ipv4: Vec::new(),
             ipv6: Vec::new(),
@@ -158,10 +219,16 @@ impl Interface {
             #[cfg(feature = "gateway")]
             gateway: None,
             #[cfg(feature = "gateway")]
+            gateways: Vec::new(),
+            #[cfg(feature = "gateway")]
             dns_servers: Vec::new(),
             mtu: None,
             #[cfg(feature = "gateway")]
             default: false,
+            #[cfg(feature = "gateway")]
+            default_v4: false,
+            #[cfg(feature = "gateway")]
+            default_v6: false,
         }
     }
     /// Returns `true` when the interface has the OS `UP` flag set.
@@ -0,0 +1,194 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Driver kind of a virtual interface, as reported by Linux `IFLA_INFO_KIND`.
+///
+/// Several kinds look identical at the link layer, for example bridges, bonds, VLANs, and
+/// veth pairs are all Ethernet devices, so `Interface::if_type` cannot tell them apart.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LinkKind {
+    /// Software bridge (`bridge`).
+    Bridge,
+    /// Bonding or link aggregation master (`bond`).
+    Bond,
+    /// 802.1Q or 802.1ad VLAN (`vlan`).
+    Vlan(VlanInfo),
+    /// One end of a virtual Ethernet pair (`veth`).
+    Veth,
+    /// MAC-based VLAN (`macvlan`).
+    MacVlan(MacVlanMode),
+    /// MAC-based VLAN with a tap character device (`macvtap`).
+    MacVtap(MacVlanMode),
+    /// IP-based VLAN (`ipvlan`).
+    IpVlan(IpVlanMode),
+    /// VXLAN overlay (`vxlan`). The VNI and endpoints are in `Interface::tunnel`.
+    Vxlan,
+    /// Geneve overlay (`geneve`). The VNI and endpoints are in `Interface::tunnel`.
+    Geneve,
+    /// WireGuard tunnel (`wireguard`).
+    WireGuard,
+    /// Layer 3 TUN device (`tun` in TUN mode).
+    Tun,
+    /// Layer 2 TAP device (`tun` in TAP mode).
+    Tap,
+    /// Dummy device (`dummy`).
+    Dummy,
+    /// Virtual routing and forwarding device (`vrf`).
+    Vrf {
+        /// Routing table bound to the device.
+        table: Option<u32>,
+    },
+    /// GRE tunnel (`gre` or `ip6gre`).
+    Gre,
+    /// Ethernet over GRE (`gretap` or `ip6gretap`).
+    GreTap,
+    /// IPv4 in IPv4 tunnel (`ipip`).
+    IpIp,
+    /// IPv6 in IPv4 tunnel (`sit`).
+    Sit,
+    /// IP in IPv6 tunnel (`ip6tnl`).
+    Ip6Tnl,
+    /// Virtual tunnel interface for IPsec (`vti` or `vti6`).
+    Vti,
+    /// XFRM interface for IPsec (`xfrm`).
+    Xfrm,
+    /// MACsec device (`macsec`).
+    MacSec,
+    /// Intermediate functional block (`ifb`).
+    Ifb,
+    /// Any other kind, with the name reported by the kernel.
+    Other(String),
+}
+
+impl LinkKind {
+    /// Returns the kernel name of this kind, as used by `ip link add type <name>`.
+    pub fn name(&self) -> &str {
+        match self {
+            LinkKind::Bridge => "bridge",
+            LinkKind::Bond => "bond",
+            LinkKind::Vlan(_) => "vlan",
+            LinkKind::Veth => "veth",
+            LinkKind::MacVlan(_) => "macvlan",
+            LinkKind::MacVtap(_) => "macvtap",
+            LinkKind::IpVlan(_) => "ipvlan",
+            LinkKind::Vxlan => "vxlan",
+            LinkKind::Geneve => "geneve",
+            LinkKind::WireGuard => "wireguard",
+            LinkKind::Tun => "tun",
+            LinkKind::Tap => "tap",
+            LinkKind::Dummy => "dummy",
+            LinkKind::Vrf { .. } => "vrf",
+            LinkKind::Gre => "gre",
+            LinkKind::GreTap => "gretap",
+            LinkKind::IpIp => "ipip",
+            LinkKind::Sit => "sit",
+            LinkKind::Ip6Tnl => "ip6tnl",
+            LinkKind::Vti => "vti",
+            LinkKind::Xfrm => "xfrm",
+            LinkKind::MacSec => "macsec",
+            LinkKind::Ifb => "ifb",
+            LinkKind::Other(name) => name,
+        }
+    }
+    /// Returns `true` for kinds that encapsulate traffic in another protocol.
+    pub fn is_tunnel(&self) -> bool {
+        matches!(
+            self,
+            LinkKind::Vxlan
+                | LinkKind::Geneve
+                | LinkKind::WireGuard
+                | LinkKind::Tun
+                | LinkKind::Gre
+                | LinkKind::GreTap
+                | LinkKind::IpIp
+                | LinkKind::Sit
+                | LinkKind::Ip6Tnl
+                | LinkKind::Vti
+                | LinkKind::Xfrm
+        )
+    }
+}
+
+impl fmt::Display for LinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// VLAN tag protocol.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VlanProtocol {
+    /// IEEE 802.1Q (`0x8100`).
+    Ieee8021Q,
+    /// IEEE 802.1ad, also known as QinQ (`0x88a8`).
+    Ieee8021Ad,
+    /// Other EtherType.
+    Other(u16),
+}
+
+impl VlanProtocol {
+    /// Returns the EtherType of this protocol.
+    pub fn value(&self) -> u16 {
+        match *self {
+            VlanProtocol::Ieee8021Q => 0x8100,
+            VlanProtocol::Ieee8021Ad => 0x88a8,
+            VlanProtocol::Other(v) => v,
+        }
+    }
+}
+
+impl From<u16> for VlanProtocol {
+    fn from(v: u16) -> Self {
+        match v {
+            0x8100 => VlanProtocol::Ieee8021Q,
+            0x88a8 => VlanProtocol::Ieee8021Ad,
+            v => VlanProtocol::Other(v),
+        }
+    }
+}
+
+/// Configuration of a VLAN interface.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VlanInfo {
+    /// VLAN identifier, from `0` to `4095`.
+    pub id: u16,
+    /// Tag protocol.
+    pub protocol: VlanProtocol,
+}
+
+/// Forwarding mode of a macvlan or macvtap interface.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MacVlanMode {
+    /// Traffic between macvlans on the same parent is dropped.
+    Private,
+    /// Traffic between macvlans is sent out and hairpinned by the switch.
+    Vepa,
+    /// Traffic between macvlans is switched locally.
+    Bridge,
+    /// The parent device is handed to a single macvlan.
+    Passthrough,
+    /// Only frames from an allowlist of source addresses are accepted.
+    Source,
+    /// Unrecognized mode value.
+    Unknown(u32),
+}
+
+/// Forwarding mode of an ipvlan interface.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IpVlanMode {
+    /// Layer 2 mode.
+    L2,
+    /// Layer 3 mode.
+    L3,
+    /// Layer 3 mode with netfilter (`l3s`).
+    L3s,
+    /// Unrecognized mode value.
+    Unknown(u16),
+}
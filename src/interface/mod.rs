@@ -1,33 +1,199 @@
+pub mod diff;
+pub mod filter;
 pub mod flags;
+pub mod graph;
 pub mod interface;
 pub mod ipv6_addr_flags;
+pub mod kind;
 pub mod mtu;
+pub mod options;
+pub mod set;
 pub mod state;
+pub mod tunnel;
 pub mod types;
 
+use crate::error::Error;
+use crate::interface::graph::InterfaceGraph;
 use crate::interface::interface::Interface;
+use crate::interface::options::CollectOptions;
+use crate::interface::set::InterfaceSet;
+use crate::net::mac::MacAddr;
+use std::net::IpAddr;
 
 #[cfg(feature = "gateway")]
-use std::net::IpAddr;
+use crate::interface::interface::DefaultInterfaces;
 
 /// Returns the interface currently used for the system's default route.
 ///
 /// This function is available when the `gateway` feature is enabled.
 ///
-/// Returns an error when no default route can be determined or when the local IP address
-/// cannot be resolved on the current platform.
+/// Returns `Error::NoDefaultRoute` when no default route can be determined, or
+/// `Error::NoLocalAddress` when the local IP address cannot be resolved on the current platform.
 #[cfg(feature = "gateway")]
-pub fn get_default_interface() -> Result<Interface, String> {
+pub fn get_default_interface() -> Result<Interface, Error> {
     resolve_default_interface(interfaces())
 }
 
+/// Returns the IPv4 and IPv6 default interfaces separately.
+///
+/// On dual-stack hosts the two default routes may leave through different interfaces, for
+/// example native IPv6 next to IPv4 over a tunnel. A family is `None` when it has no default
+/// route.
+///
+/// This function is available when the `gateway` feature is enabled.
+#[cfg(feature = "gateway")]
+pub fn get_default_interfaces() -> DefaultInterfaces {
+    let ifaces = interfaces();
+    DefaultInterfaces {
+        ipv4: ifaces.iter().find(|iface| iface.default_v4).cloned(),
+        ipv6: ifaces.into_iter().find(|iface| iface.default_v6),
+    }
+}
+
 /// Returns a list of the network interfaces.
 ///
-/// Each `Interface` contains the data that could be collected at discovery time.
+/// Each `Interface` contains the data that could be collected at discovery time. Interfaces
+/// are sorted by `Interface::index`.
 pub fn get_interfaces() -> Vec<Interface> {
     interfaces()
 }
 
+/// Returns the network interfaces as an `InterfaceSet`, which supports lookups by index,
+/// name, and MAC address.
+pub fn get_interface_set() -> InterfaceSet {
+    InterfaceSet::new(interfaces())
+}
+
+/// Returns the dependency graph of the network interfaces.
+///
+/// The graph records which interfaces are enslaved to a bond, bridge, or VRF, and which are
+/// stacked on a parent, such as VLANs, macvlans, and tunnels bound to an underlay device.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the link dump fails, and
+/// with `Error::Unsupported` on platforms other than Linux.
+pub fn get_interface_graph() -> Result<InterfaceGraph, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::graph::interface_graph().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+/// Returns a list of the network interfaces, collecting only the metadata selected by
+/// `options`.
+///
+/// Use `CollectOptions::minimal` when only names, indexes, and addresses are needed. On Linux
+/// this skips the per-interface sysfs, DHCP lease, and route lookups that dominate the cost of
+/// `get_interfaces` on hosts with many interfaces.
+pub fn get_interfaces_with(options: &CollectOptions) -> Vec<Interface> {
+    #[cfg(target_os = "linux")]
+    let ifaces = crate::os::linux::interface::interfaces_with(options);
+    #[cfg(not(target_os = "linux"))]
+    let ifaces = platform_interfaces();
+
+    let mut ifaces = finish_interfaces(ifaces);
+    for iface in &mut ifaces {
+        options.strip(iface);
+    }
+    ifaces
+}
+
+/// Returns the interface with the given system name.
+///
+/// On Linux this requests only the matching link and its addresses, routes, and neighbours
+/// from the kernel, and only that interface is enriched with sysfs, DHCP, and DNS data.
+/// Other platforms filter a full enumeration.
+pub fn get_interface_by_name(name: &str) -> Option<Interface> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::interface::interface_by_name(name)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        interfaces().into_iter().find(|iface| iface.name == name)
+    }
+}
+
+/// Returns the interface with the given index.
+///
+/// See `get_interface_by_name` for how the lookup avoids a full enumeration.
+pub fn get_interface_by_index(index: u32) -> Option<Interface> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::interface::interface_by_index(index)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        interfaces().into_iter().find(|iface| iface.index == index)
+    }
+}
+
+/// Returns the first interface with the given link-layer address.
+///
+/// Several interfaces can share a MAC address, for example a bond and its ports or VLANs on
+/// top of a physical device. On Linux the links are scanned without their addresses, so this
+/// is still cheaper than `get_interfaces`.
+pub fn get_interface_by_mac(mac_addr: MacAddr) -> Option<Interface> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::interface::interface_by_mac(mac_addr)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        interfaces()
+            .into_iter()
+            .find(|iface| iface.mac_addr == Some(mac_addr))
+    }
+}
+
+/// Returns the interface that has `ip_addr` assigned.
+///
+/// On Linux the addresses are scanned without enriching any interface, and only the owning
+/// interface is collected in full.
+pub fn get_interface_by_ip(ip_addr: IpAddr) -> Option<Interface> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::interface::interface_by_ip(ip_addr)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        interfaces().into_iter().find(|iface| match ip_addr {
+            IpAddr::V4(v4) => iface.ipv4.iter().any(|n| n.addr() == v4),
+            IpAddr::V6(v6) => iface.ipv6.iter().any(|n| n.addr() == v6),
+        })
+    }
+}
+
+/// Returns a list of the network interfaces, reporting backend failures.
+///
+/// `get_interfaces` silently falls back to a less detailed source, or returns an empty list,
+/// when the primary backend fails. This function returns the failure instead, so an empty
+/// result means the system has no interfaces.
+///
+/// On Linux and Android this fails with `Error::Netlink` or `Error::PermissionDenied` when
+/// the netlink enumeration fails. Other platforms currently never fail.
+pub fn try_get_interfaces() -> Result<Vec<Interface>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::interface::try_interfaces()
+            .map(finish_interfaces)
+            .map_err(Error::netlink)
+    }
+    #[cfg(target_os = "android")]
+    {
+        crate::os::android::interface::try_interfaces()
+            .map(finish_interfaces)
+            .map_err(Error::netlink)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        Ok(interfaces())
+    }
+}
+
 /// Pick the interface index corresponding to the system's default route.
 /// Prefers exact IP match; falls back to subnet containment.
 #[cfg(feature = "gateway")]
@@ -58,15 +224,7 @@ pub(crate) fn pick_default_iface_index(ifaces: &[Interface], local_ip: IpAddr) -
 }
 
 #[cfg(feature = "gateway")]
-pub(crate) fn iface_has_ip(iface: &Interface, local_ip: IpAddr) -> bool {
-    match local_ip {
-        IpAddr::V4(ipv4) => iface.ipv4.iter().any(|x| x.addr() == ipv4),
-        IpAddr::V6(ipv6) => iface.ipv6.iter().any(|x| x.addr() == ipv6),
-    }
-}
-
-#[cfg(feature = "gateway")]
-pub(crate) fn resolve_default_interface(mut ifaces: Vec<Interface>) -> Result<Interface, String> {
+pub(crate) fn resolve_default_interface(mut ifaces: Vec<Interface>) -> Result<Interface, Error> {
     use crate::net::ip::get_local_ipaddr;
 
     if let Some(pos) = ifaces.iter().position(|iface| iface.default) {
@@ -75,18 +233,64 @@ pub(crate) fn resolve_default_interface(mut ifaces: Vec<Interface>) -> Result<In
 
     let local_ip: IpAddr = match get_local_ipaddr() {
         Some(local_ip) => local_ip,
-        None => return Err(String::from("Local IP address not found")),
+        None => return Err(Error::NoLocalAddress),
     };
 
-    let idx = pick_default_iface_index(&ifaces, local_ip)
-        .ok_or_else(|| String::from("Default interface not found"))?;
+    let idx = pick_default_iface_index(&ifaces, local_ip).ok_or(Error::NoDefaultRoute)?;
     ifaces
         .into_iter()
         .find(|iface| iface.index == idx)
-        .ok_or_else(|| String::from("Default interface not found"))
+        .ok_or(Error::NoDefaultRoute)
 }
 
 pub(crate) fn interfaces() -> Vec<Interface> {
+    finish_interfaces(platform_interfaces())
+}
+
+/// Fills fields that are derived the same way on every platform.
+fn finish_interfaces(mut ifaces: Vec<Interface>) -> Vec<Interface> {
+    // Platform backends collect through hash maps, so their order is not stable.
+    ifaces.sort_by_key(|iface| iface.index);
+    #[cfg(feature = "gateway")]
+    {
+        // Platforms without per-router route data only fill `Interface::gateway`.
+        for iface in &mut ifaces {
+            if iface.gateways.is_empty()
+                && let Some(device) = &iface.gateway
+            {
+                iface.gateways = crate::net::device::gateways_from_device(device);
+            }
+        }
+        #[cfg(not(target_os = "linux"))]
+        mark_family_defaults(&mut ifaces);
+    }
+    ifaces
+}
+
+/// Marks the per-family default interfaces on platforms that did not report them, using the
+/// source address the OS selects for each family.
+///
+/// Linux resolves the defaults with kernel route lookups instead, and uses this heuristic only
+/// when a lookup fails.
+#[cfg(all(feature = "gateway", not(target_os = "linux")))]
+fn mark_family_defaults(ifaces: &mut [Interface]) {
+    use crate::net::ip::{try_ipv4, try_ipv6};
+
+    if !ifaces.iter().any(|iface| iface.default_v4)
+        && let Some(idx) = try_ipv4().and_then(|ip| pick_default_iface_index(ifaces, ip))
+        && let Some(iface) = ifaces.iter_mut().find(|iface| iface.index == idx)
+    {
+        iface.default_v4 = true;
+    }
+    if !ifaces.iter().any(|iface| iface.default_v6)
+        && let Some(idx) = try_ipv6().and_then(|ip| pick_default_iface_index(ifaces, ip))
+        && let Some(iface) = ifaces.iter_mut().find(|iface| iface.index == idx)
+    {
+        iface.default_v6 = true;
+    }
+}
+
+fn platform_interfaces() -> Vec<Interface> {
     #[cfg(target_os = "linux")]
     {
         crate::os::linux::interface::interfaces()
@@ -177,4 +381,31 @@ mod tests {
         let local = IpAddr::V4(Ipv4Addr::new(172, 16, 0, 1));
         assert_eq!(pick_default_iface_index(&[a], local), None);
     }
+
+    #[test]
+    fn split_defaults_are_reported() {
+        use crate::interface::interface::DefaultInterfaces;
+
+        let mut a = Interface::dummy();
+        a.index = 2;
+        let mut b = Interface::dummy();
+        b.index = 3;
+
+        let split = DefaultInterfaces {
+            ipv4: Some(a.clone()),
+            ipv6: Some(b),
+        };
+        let shared = DefaultInterfaces {
+            ipv4: Some(a.clone()),
+            ipv6: Some(a.clone()),
+        };
+        let v4_only = DefaultInterfaces {
+            ipv4: Some(a),
+            ipv6: None,
+        };
+
+        assert!(split.is_split());
+        assert!(!shared.is_split());
+        assert!(!v4_only.is_split());
+    }
 }
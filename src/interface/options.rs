@@ -0,0 +1,142 @@
+use crate::interface::interface::Interface;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Selects which optional metadata `get_interfaces_with` collects.
+///
+/// Names, indexes, flags, MTU, and addresses are always collected. Every other field is
+/// gathered only when its option is enabled, and is left empty (`None`, empty vector, or a
+/// coarse value) otherwise. On Linux disabled options skip the corresponding sysfs, procfs,
+/// netlink, and configuration file reads. Other platforms collect everything and clear the
+/// fields afterwards.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CollectOptions {
+    /// Collect `Interface::stats`.
+    pub stats: bool,
+    /// Collect `Interface::dhcp_v4_enabled` and `Interface::dhcp_v6_enabled`.
+    pub dhcp: bool,
+    /// Collect `Interface::dns_servers`.
+    ///
+    /// This option has an effect only with the `gateway` feature.
+    pub dns: bool,
+    /// Collect `Interface::gateway` and `Interface::gateways`, and mark the default
+    /// interfaces with `Interface::default`, `Interface::default_v4`, and
+    /// `Interface::default_v6`.
+    ///
+    /// This option has an effect only with the `gateway` feature.
+    pub gateway: bool,
+    /// Collect `Interface::transmit_speed` and `Interface::receive_speed`.
+    pub link_speed: bool,
+    /// Refine `Interface::if_type`, for example to tell Wi-Fi and WWAN devices apart from
+    /// Ethernet.
+    ///
+    /// When disabled the type is derived from the link-layer type alone.
+    pub type_refinement: bool,
+}
+
+impl CollectOptions {
+    /// Collects every field. This is what `get_interfaces` does.
+    pub fn all() -> CollectOptions {
+        CollectOptions {
+            stats: true,
+            dhcp: true,
+            dns: true,
+            gateway: true,
+            link_speed: true,
+            type_refinement: true,
+        }
+    }
+
+    /// Collects only names, indexes, flags, MTU, and addresses.
+    pub fn minimal() -> CollectOptions {
+        CollectOptions {
+            stats: false,
+            dhcp: false,
+            dns: false,
+            gateway: false,
+            link_speed: false,
+            type_refinement: false,
+        }
+    }
+
+    /// Clears the fields of `iface` that were not requested.
+    pub(crate) fn strip(&self, iface: &mut Interface) {
+        if !self.stats {
+            iface.stats = None;
+        }
+        if !self.dhcp {
+            iface.dhcp_v4_enabled = None;
+            iface.dhcp_v6_enabled = None;
+        }
+        if !self.link_speed {
+            iface.transmit_speed = None;
+            iface.receive_speed = None;
+        }
+        #[cfg(feature = "gateway")]
+        {
+            if !self.dns {
+                iface.dns_servers.clear();
+            }
+            if !self.gateway {
+                iface.gateway = None;
+                iface.gateways.clear();
+                iface.default = false;
+                iface.default_v4 = false;
+                iface.default_v6 = false;
+            }
+        }
+    }
+}
+
+impl Default for CollectOptions {
+    /// Same as `CollectOptions::all`.
+    fn default() -> Self {
+        CollectOptions::all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CollectOptions;
+    use crate::interface::interface::Interface;
+    use crate::stats::counters::{CounterWidth, InterfaceStats};
+
+    #[test]
+    fn strip_clears_unrequested_fields() {
+        let mut iface = Interface::dummy();
+        iface.stats = Some(InterfaceStats {
+            rx_bytes: 1,
+            tx_bytes: 2,
+            timestamp: None,
+            counter_width: CounterWidth::Bits64,
+            extended: None,
+        });
+        iface.dhcp_v4_enabled = Some(true);
+        iface.transmit_speed = Some(1_000_000_000);
+        iface.mtu = Some(1500);
+
+        let mut kept = iface.clone();
+        CollectOptions::all().strip(&mut kept);
+        assert_eq!(kept, iface);
+
+        CollectOptions::minimal().strip(&mut iface);
+        assert!(iface.stats.is_none());
+        assert!(iface.dhcp_v4_enabled.is_none());
+        assert!(iface.transmit_speed.is_none());
+        assert_eq!(iface.mtu, Some(1500));
+    }
+
+    #[test]
+    fn minimal_enumeration_keeps_addresses() {
+        let ifaces = crate::interface::get_interfaces_with(&CollectOptions::minimal());
+        for iface in &ifaces {
+            assert!(iface.stats.is_none());
+            assert!(iface.receive_speed.is_none());
+        }
+        if let Some(lo) = ifaces.iter().find(|iface| iface.is_loopback()) {
+            assert!(!lo.ipv4.is_empty() || !lo.ipv6.is_empty());
+        }
+    }
+}
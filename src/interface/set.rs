@@ -0,0 +1,194 @@
+use crate::interface::interface::Interface;
+use crate::net::mac::MacAddr;
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A collection of interfaces ordered by index, with constant-time lookups.
+///
+/// Iteration always yields interfaces in ascending `Interface::index` order, so two
+/// enumerations of an unchanged system produce identical output. The set serializes as a plain
+/// sequence of interfaces, and the lookup tables are rebuilt on deserialization.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<Interface>", into = "Vec<Interface>")
+)]
+pub struct InterfaceSet {
+    interfaces: Vec<Interface>,
+    by_index: HashMap<u32, usize>,
+    by_name: HashMap<String, usize>,
+    by_mac: HashMap<MacAddr, usize>,
+}
+
+impl InterfaceSet {
+    /// Builds a set from `interfaces`, sorting them by index.
+    ///
+    /// When several interfaces share a MAC address, such as a bond and its ports, the MAC
+    /// lookup returns the one with the lowest index.
+    pub fn new(mut interfaces: Vec<Interface>) -> InterfaceSet {
+        interfaces.sort_by_key(|iface| iface.index);
+
+        let mut by_index = HashMap::with_capacity(interfaces.len());
+        let mut by_name = HashMap::with_capacity(interfaces.len());
+        let mut by_mac = HashMap::new();
+        for (pos, iface) in interfaces.iter().enumerate() {
+            by_index.entry(iface.index).or_insert(pos);
+            by_name.entry(iface.name.clone()).or_insert(pos);
+            if let Some(mac) = iface.mac_addr
+                && mac != MacAddr::zero()
+            {
+                by_mac.entry(mac).or_insert(pos);
+            }
+        }
+
+        InterfaceSet {
+            interfaces,
+            by_index,
+            by_name,
+            by_mac,
+        }
+    }
+    /// Returns the number of interfaces.
+    pub fn len(&self) -> usize {
+        self.interfaces.len()
+    }
+    /// Returns `true` when the set contains no interfaces.
+    pub fn is_empty(&self) -> bool {
+        self.interfaces.is_empty()
+    }
+    /// Returns an iterator over the interfaces in index order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Interface> {
+        self.interfaces.iter()
+    }
+    /// Returns the interfaces as a slice in index order.
+    pub fn as_slice(&self) -> &[Interface] {
+        &self.interfaces
+    }
+    /// Returns the interfaces as a vector in index order.
+    pub fn into_vec(self) -> Vec<Interface> {
+        self.interfaces
+    }
+    /// Returns the interface with the given index.
+    pub fn get_by_index(&self, index: u32) -> Option<&Interface> {
+        self.by_index.get(&index).map(|&pos| &self.interfaces[pos])
+    }
+    /// Returns the interface with the given system name.
+    pub fn get_by_name(&self, name: &str) -> Option<&Interface> {
+        self.by_name.get(name).map(|&pos| &self.interfaces[pos])
+    }
+    /// Returns the lowest-indexed interface with the given MAC address.
+    pub fn get_by_mac(&self, mac_addr: &MacAddr) -> Option<&Interface> {
+        self.by_mac.get(mac_addr).map(|&pos| &self.interfaces[pos])
+    }
+    /// Returns the interface used for the system's default route.
+    ///
+    /// This method is available when the `gateway` feature is enabled.
+    #[cfg(feature = "gateway")]
+    pub fn default_interface(&self) -> Option<&Interface> {
+        self.interfaces.iter().find(|iface| iface.default)
+    }
+    /// Returns the interface used for the IPv4 default route.
+    ///
+    /// This method is available when the `gateway` feature is enabled.
+    #[cfg(feature = "gateway")]
+    pub fn default_ipv4(&self) -> Option<&Interface> {
+        self.interfaces.iter().find(|iface| iface.default_v4)
+    }
+    /// Returns the interface used for the IPv6 default route.
+    ///
+    /// This method is available when the `gateway` feature is enabled.
+    #[cfg(feature = "gateway")]
+    pub fn default_ipv6(&self) -> Option<&Interface> {
+        self.interfaces.iter().find(|iface| iface.default_v6)
+    }
+}
+
+impl From<Vec<Interface>> for InterfaceSet {
+    fn from(interfaces: Vec<Interface>) -> Self {
+        InterfaceSet::new(interfaces)
+    }
+}
+
+impl From<InterfaceSet> for Vec<Interface> {
+    fn from(set: InterfaceSet) -> Self {
+        set.interfaces
+    }
+}
+
+impl FromIterator<Interface> for InterfaceSet {
+    fn from_iter<I: IntoIterator<Item = Interface>>(iter: I) -> Self {
+        InterfaceSet::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for InterfaceSet {
+    type Item = Interface;
+    type IntoIter = std::vec::IntoIter<Interface>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.interfaces.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a InterfaceSet {
+    type Item = &'a Interface;
+    type IntoIter = std::slice::Iter<'a, Interface>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.interfaces.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InterfaceSet;
+    use crate::interface::interface::Interface;
+    use crate::net::mac::MacAddr;
+
+    fn iface(index: u32, name: &str, mac: Option<MacAddr>) -> Interface {
+        let mut iface = Interface::dummy();
+        iface.index = index;
+        iface.name = name.to_string();
+        iface.mac_addr = mac;
+        iface
+    }
+
+    #[test]
+    fn sorts_by_index_and_indexes_lookups() {
+        let mac = MacAddr::new(0x02, 0, 0, 0, 0, 1);
+        let set: InterfaceSet = vec![
+            iface(5, "bond0", Some(mac)),
+            iface(1, "lo", Some(MacAddr::zero())),
+            iface(3, "eth0", Some(mac)),
+        ]
+        .into_iter()
+        .collect();
+
+        let order: Vec<u32> = set.iter().map(|i| i.index).collect();
+        assert_eq!(order, vec![1, 3, 5]);
+        assert_eq!(set.get_by_index(5).map(|i| i.name.as_str()), Some("bond0"));
+        assert_eq!(set.get_by_name("lo").map(|i| i.index), Some(1));
+        assert_eq!(set.get_by_mac(&mac).map(|i| i.index), Some(3));
+        assert!(set.get_by_mac(&MacAddr::zero()).is_none());
+        assert!(set.get_by_index(2).is_none());
+
+        let names: Vec<String> = set.into_iter().map(|i| i.name).collect();
+        assert_eq!(names, vec!["lo", "eth0", "bond0"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_as_sequence_and_rebuilds_indexes() {
+        let set = InterfaceSet::new(vec![iface(2, "eth0", None), iface(1, "lo", None)]);
+
+        let json = serde_json::to_string(&set).unwrap();
+        let restored: InterfaceSet = serde_json::from_str(&json).unwrap();
+
+        assert!(json.starts_with('['));
+        assert_eq!(restored, set);
+        assert_eq!(restored.get_by_name("eth0").map(|i| i.index), Some(2));
+    }
+}
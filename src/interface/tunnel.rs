@@ -0,0 +1,73 @@
+use std::net::IpAddr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// UDP encapsulation applied around a GRE or IP tunnel.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TunnelEncap {
+    /// Foo-over-UDP.
+    Fou,
+    /// Generic UDP encapsulation.
+    Gue,
+    /// MPLS over UDP.
+    Mpls,
+    /// Unrecognized encapsulation type.
+    Other(u16),
+}
+
+/// Encapsulation options of a tunnel.
+///
+/// All fields default to `false` when the tunnel kind does not support the option.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TunnelFlags {
+    /// Outgoing packets carry a checksum, either the GRE checksum or the UDP checksum of
+    /// VXLAN and Geneve.
+    pub checksum: bool,
+    /// Outgoing GRE packets carry sequence numbers.
+    pub sequence: bool,
+    /// Remote checksum offload is enabled.
+    pub remote_checksum: bool,
+    /// The tunnel takes its endpoints from per-packet metadata (`external` mode).
+    pub collect_metadata: bool,
+    /// The outer TTL is copied from the inner packet.
+    pub ttl_inherit: bool,
+    /// Path MTU discovery is enabled.
+    pub pmtu_discovery: bool,
+}
+
+/// Endpoints and parameters of a tunnel interface.
+///
+/// Fields are `None` when the tunnel kind has no such parameter or the kernel does not
+/// report it.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TunnelInfo {
+    /// Local endpoint address.
+    pub local: Option<IpAddr>,
+    /// Remote endpoint address, or multicast group of a VXLAN.
+    pub remote: Option<IpAddr>,
+    /// GRE key expected on received packets.
+    pub input_key: Option<u32>,
+    /// GRE key set on sent packets.
+    pub output_key: Option<u32>,
+    /// VXLAN or Geneve virtual network identifier.
+    pub vni: Option<u32>,
+    /// Destination UDP port of a VXLAN or Geneve, or of the UDP encapsulation.
+    pub port: Option<u16>,
+    /// Outer TTL, `None` when inherited from the inner packet or left to the route default.
+    pub ttl: Option<u8>,
+    /// Outer type of service, or traffic class of an IPv6 tunnel, `None` when inherited
+    /// from the inner packet.
+    pub tos: Option<u8>,
+    /// Outer flow label of an IPv6 tunnel, `None` when inherited from the inner packet.
+    pub flowlabel: Option<u32>,
+    /// Index of the interface the encapsulated packets are bound to.
+    pub underlay_index: Option<u32>,
+    /// UDP encapsulation of a GRE or IP tunnel.
+    pub encap: Option<TunnelEncap>,
+    /// Encapsulation options.
+    pub flags: TunnelFlags,
+}
@@ -5,10 +5,17 @@
 //!
 //! Main entry points:
 //! - [`get_interfaces`] returns a snapshot of all visible interfaces.
+//!   [`try_get_interfaces`] does the same but reports backend failures as [`Error`].
 //! - [`Interface`] represents one interface and its collected metadata.
-//! - [`get_default_interface`] and [`get_default_gateway`] are available with the `gateway` feature (default).
+//! - [`get_default_interface`], [`get_default_interfaces`], and [`get_default_gateway`] are
+//!   available with the `gateway` feature (default).
+//! - [`route::get_routes`] returns the system routing table with the `gateway` feature.
+//! - [`monitor::Monitor`] reports network configuration changes with the `gateway` feature.
 //!
+pub mod error;
 pub mod interface;
+#[cfg(feature = "gateway")]
+pub mod monitor;
 pub mod net;
 mod os;
 pub mod prelude;
@@ -18,12 +25,30 @@ pub mod stats;
 
 pub use ipnet;
 
+pub use error::Error;
+pub use interface::filter::InterfaceFilter;
+pub use interface::get_interface_graph;
+pub use interface::get_interface_set;
 pub use interface::get_interfaces;
+pub use interface::get_interfaces_with;
+pub use interface::graph::InterfaceGraph;
 pub use interface::interface::Interface;
+pub use interface::kind::LinkKind;
+pub use interface::options::CollectOptions;
+pub use interface::set::InterfaceSet;
+pub use interface::try_get_interfaces;
+pub use interface::tunnel::TunnelInfo;
+pub use interface::{
+    get_interface_by_index, get_interface_by_ip, get_interface_by_mac, get_interface_by_name,
+};
 pub use net::device::NetworkDevice;
 pub use net::mac::MacAddr;
+pub use stats::sampler::StatsSampler;
+pub use stats::{get_all_stats, update_all_stats};
 
 #[cfg(feature = "gateway")]
 pub use interface::get_default_interface;
 #[cfg(feature = "gateway")]
+pub use interface::get_default_interfaces;
+#[cfg(feature = "gateway")]
 pub use route::get_default_gateway;
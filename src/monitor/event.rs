@@ -0,0 +1,91 @@
+use crate::interface::state::OperState;
+use crate::net::neighbour::Neighbour;
+use crate::route::table::Route;
+use crate::route::types::IpFamily;
+use ipnet::IpNet;
+use std::net::IpAddr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A change to the network configuration reported by the kernel.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NetworkEvent {
+    /// A new interface appeared.
+    LinkAdded {
+        /// Index of the interface.
+        if_index: u32,
+        /// Name of the interface.
+        if_name: String,
+    },
+    /// An interface was removed.
+    LinkRemoved {
+        /// Index of the removed interface.
+        if_index: u32,
+        /// Last known name of the interface.
+        if_name: Option<String>,
+    },
+    /// An interface was renamed.
+    LinkRenamed {
+        /// Index of the interface.
+        if_index: u32,
+        /// Previous name.
+        old_name: String,
+        /// Current name.
+        new_name: String,
+    },
+    /// The operational state of an interface changed.
+    OperStateChanged {
+        /// Index of the interface.
+        if_index: u32,
+        /// Name of the interface, when known.
+        if_name: Option<String>,
+        /// Previous state.
+        old_state: OperState,
+        /// Current state.
+        new_state: OperState,
+    },
+    /// An address was assigned to an interface.
+    AddressAdded {
+        /// Index of the interface.
+        if_index: u32,
+        /// Name of the interface, when known.
+        if_name: Option<String>,
+        /// Address and prefix length.
+        addr: IpNet,
+    },
+    /// An address was removed from an interface.
+    AddressRemoved {
+        /// Index of the interface.
+        if_index: u32,
+        /// Name of the interface, when known.
+        if_name: Option<String>,
+        /// Address and prefix length.
+        addr: IpNet,
+    },
+    /// A route was added or replaced.
+    RouteAdded(Route),
+    /// A route was removed.
+    RouteRemoved(Route),
+    /// The route selected for Internet-bound traffic of one family changed.
+    ///
+    /// Both fields are `None` when the family no longer has a default route.
+    DefaultGatewayChanged {
+        /// Address family of the default route.
+        family: IpFamily,
+        /// Gateway of the new default route.
+        gateway: Option<IpAddr>,
+        /// Output interface of the new default route.
+        if_index: Option<u32>,
+    },
+    /// A neighbour cache entry was added or updated.
+    NeighbourChanged(Neighbour),
+    /// A neighbour cache entry was removed.
+    NeighbourRemoved(Neighbour),
+    /// The kernel dropped notifications because they were not read fast enough.
+    ///
+    /// Tracked state is refreshed before monitoring continues, but changes made in the gap
+    /// are not reported. Take a new snapshot when this is received.
+    EventsDropped,
+}
@@ -0,0 +1,89 @@
+pub mod event;
+
+use crate::error::Error;
+use std::ops::ControlFlow;
+
+pub use self::event::NetworkEvent;
+
+/// A subscription to network configuration changes.
+///
+/// On Linux this listens to the rtnetlink multicast groups for links, IPv4 and IPv6
+/// addresses, IPv4 and IPv6 routes, and neighbours. The monitor keeps enough state to report
+/// renames, operational state transitions, and default gateway changes.
+///
+/// `Monitor` is a blocking iterator over events. Use `Monitor::run` or `watch` to handle
+/// events with a callback instead.
+pub struct Monitor {
+    #[cfg(target_os = "linux")]
+    inner: crate::os::linux::monitor::RtnlMonitor,
+    failed: bool,
+}
+
+impl Monitor {
+    /// Subscribes to network change notifications.
+    ///
+    /// Fails with `Error::Netlink` or `Error::PermissionDenied` when the subscription or the
+    /// initial snapshot fails, and with `Error::Unsupported` on platforms without a change
+    /// notification backend.
+    pub fn new() -> Result<Monitor, Error> {
+        #[cfg(target_os = "linux")]
+        {
+            Ok(Monitor {
+                inner: crate::os::linux::monitor::RtnlMonitor::new().map_err(Error::netlink)?,
+                failed: false,
+            })
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            Err(Error::Unsupported)
+        }
+    }
+
+    /// Blocks until the next event is available.
+    pub fn next_event(&mut self) -> Result<NetworkEvent, Error> {
+        #[cfg(target_os = "linux")]
+        {
+            self.inner.next_event().map_err(Error::netlink)
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            Err(Error::Unsupported)
+        }
+    }
+
+    /// Passes every event to `f` until it returns `ControlFlow::Break` or receiving fails.
+    pub fn run<F>(mut self, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(NetworkEvent) -> ControlFlow<()>,
+    {
+        loop {
+            if f(self.next_event()?).is_break() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl Iterator for Monitor {
+    type Item = Result<NetworkEvent, Error>;
+
+    /// Blocks until the next event. After an error, the iterator ends.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let event = self.next_event();
+        self.failed = event.is_err();
+        Some(event)
+    }
+}
+
+/// Subscribes to network changes and passes every event to `f`.
+///
+/// This is a shorthand for `Monitor::new()?.run(f)`.
+pub fn watch<F>(f: F) -> Result<(), Error>
+where
+    F: FnMut(NetworkEvent) -> ControlFlow<()>,
+{
+    Monitor::new()?.run(f)
+}
@@ -0,0 +1,194 @@
+use crate::error::Error;
+use crate::net::mac::MacAddr;
+use std::fmt;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bonding mode, which selects how traffic is spread over the slaves.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BondMode {
+    /// Round-robin over all slaves (`balance-rr`).
+    BalanceRr,
+    /// One active slave, the others stand by (`active-backup`).
+    ActiveBackup,
+    /// Slave selected by the transmit hash policy (`balance-xor`).
+    BalanceXor,
+    /// Every frame is sent on every slave (`broadcast`).
+    Broadcast,
+    /// IEEE 802.3ad dynamic link aggregation with LACP (`802.3ad`).
+    Ieee8023Ad,
+    /// Adaptive transmit load balancing (`balance-tlb`).
+    BalanceTlb,
+    /// Adaptive transmit and receive load balancing (`balance-alb`).
+    BalanceAlb,
+    /// Unrecognized mode value.
+    Other(u8),
+}
+
+impl BondMode {
+    /// Returns the name used by the `mode` bonding option.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BondMode::BalanceRr => "balance-rr",
+            BondMode::ActiveBackup => "active-backup",
+            BondMode::BalanceXor => "balance-xor",
+            BondMode::Broadcast => "broadcast",
+            BondMode::Ieee8023Ad => "802.3ad",
+            BondMode::BalanceTlb => "balance-tlb",
+            BondMode::BalanceAlb => "balance-alb",
+            BondMode::Other(_) => "other",
+        }
+    }
+}
+
+impl fmt::Display for BondMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Link state of a slave, as seen by the MII or carrier monitor.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MiiStatus {
+    /// The link is up.
+    Up,
+    /// The link went down and the down delay is running.
+    GoingDown,
+    /// The link is down.
+    Down,
+    /// The link came back and the up delay is running.
+    GoingBack,
+    /// Unrecognized status value.
+    Other(u8),
+}
+
+/// Role of a slave in the bond.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SlaveState {
+    /// The slave carries traffic.
+    Active,
+    /// The slave stands by.
+    Backup,
+    /// Unrecognized state value.
+    Other(u8),
+}
+
+/// Rate at which the link partner is asked to send LACPDUs.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LacpRate {
+    /// Every 30 seconds.
+    Slow,
+    /// Every second.
+    Fast,
+}
+
+/// The active 802.3ad aggregator of a bond.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdAggregator {
+    /// Aggregator identifier.
+    pub id: u16,
+    /// Number of slaves in the aggregator.
+    pub num_ports: u16,
+    /// Operational key of the local system.
+    pub actor_key: u16,
+    /// Operational key of the link partner.
+    pub partner_key: u16,
+    /// System address of the link partner, usually the switch.
+    pub partner_mac: MacAddr,
+}
+
+/// A slave of a bond.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BondSlave {
+    /// Index of the slave interface.
+    pub index: u32,
+    /// Name of the slave interface.
+    pub name: String,
+    /// Role of the slave.
+    pub state: Option<SlaveState>,
+    /// Link state of the slave.
+    pub mii_status: MiiStatus,
+    /// Number of times the slave link went down.
+    pub link_failure_count: u32,
+    /// Hardware address of the slave before it joined the bond.
+    pub perm_mac_addr: Option<MacAddr>,
+    /// Transmit queue the slave is mapped to.
+    pub queue_id: Option<u16>,
+    /// 802.3ad aggregator the slave belongs to.
+    pub aggregator_id: Option<u16>,
+    /// 802.3ad operational port state of the local end.
+    pub actor_port_state: Option<u8>,
+    /// 802.3ad operational port state of the link partner.
+    pub partner_port_state: Option<u16>,
+}
+
+/// A bonding interface with its configuration and slaves.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bond {
+    /// Index of the bond interface.
+    pub index: u32,
+    /// Name of the bond interface.
+    pub name: String,
+    /// Bonding mode.
+    pub mode: BondMode,
+    /// Index of the slave currently carrying traffic, in modes with a single active slave.
+    pub active_slave: Option<u32>,
+    /// Index of the preferred slave.
+    pub primary: Option<u32>,
+    /// Link monitoring interval, `None` when MII monitoring is disabled.
+    pub miimon: Option<Duration>,
+    /// Delay before a slave that came up is used.
+    pub up_delay: Option<Duration>,
+    /// Delay before a slave that went down is disabled.
+    pub down_delay: Option<Duration>,
+    /// Transmit hash policy, as named by the `xmit_hash_policy` bonding option.
+    pub xmit_hash_policy: Option<String>,
+    /// LACPDU rate requested from the link partner.
+    pub lacp_rate: Option<LacpRate>,
+    /// Minimum number of slaves that must be up for the bond to have carrier.
+    pub min_links: Option<u32>,
+    /// Active 802.3ad aggregator.
+    pub aggregator: Option<AdAggregator>,
+    /// Slaves of the bond, ordered by index.
+    pub slaves: Vec<BondSlave>,
+}
+
+impl Bond {
+    /// Returns the slave with the given interface index.
+    pub fn slave(&self, index: u32) -> Option<&BondSlave> {
+        self.slaves.iter().find(|slave| slave.index == index)
+    }
+    /// Returns the slaves whose link is up.
+    pub fn slaves_up(&self) -> impl Iterator<Item = &BondSlave> {
+        self.slaves
+            .iter()
+            .filter(|slave| slave.mii_status == MiiStatus::Up)
+    }
+}
+
+/// Returns every bonding interface with its configuration and slaves.
+///
+/// On Linux the details come from netlink. `/proc/net/bonding` is read instead when netlink is
+/// unavailable or omits the bonding attributes.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the link dump fails and the
+/// procfs fallback is unavailable, and with `Error::Unsupported` on platforms other than Linux.
+pub fn bonds() -> Result<Vec<Bond>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::bond::bonds().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
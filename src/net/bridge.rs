@@ -0,0 +1,296 @@
+use crate::error::Error;
+use crate::interface::kind::VlanProtocol;
+use crate::net::mac::MacAddr;
+use std::fmt;
+use std::net::IpAddr;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Spanning tree mode of a bridge.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StpState {
+    /// Spanning tree is disabled.
+    Disabled,
+    /// The kernel runs the spanning tree protocol.
+    Kernel,
+    /// A user space daemon, such as `mstpd`, runs the spanning tree protocol.
+    User,
+    /// Unrecognized state value.
+    Other(u32),
+}
+
+/// Spanning tree state of a bridge port.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PortState {
+    /// The port is administratively or operationally down.
+    Disabled,
+    /// The port takes part in the election but does not learn or forward.
+    Listening,
+    /// The port learns addresses but does not forward.
+    Learning,
+    /// The port learns addresses and forwards frames.
+    Forwarding,
+    /// The port is blocked to prevent a loop.
+    Blocking,
+    /// Unrecognized state value.
+    Other(u8),
+}
+
+impl PortState {
+    /// Returns the lowercase representation used by `bridge link`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PortState::Disabled => "disabled",
+            PortState::Listening => "listening",
+            PortState::Learning => "learning",
+            PortState::Forwarding => "forwarding",
+            PortState::Blocking => "blocking",
+            PortState::Other(_) => "other",
+        }
+    }
+}
+
+impl fmt::Display for PortState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Spanning tree bridge identifier.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BridgeId {
+    /// Bridge priority.
+    pub priority: u16,
+    /// Bridge address.
+    pub mac_addr: MacAddr,
+}
+
+impl fmt::Display for BridgeId {
+    /// Formats the identifier as `8000.aabbccddeeff`, as printed by `ip -d link`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}.", self.priority)?;
+        for octet in self.mac_addr.octets() {
+            write!(f, "{:02x}", octet)?;
+        }
+        Ok(())
+    }
+}
+
+/// A port of a bridge.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BridgePort {
+    /// Index of the port interface.
+    pub index: u32,
+    /// Name of the port interface.
+    pub name: String,
+    /// Spanning tree state.
+    pub state: PortState,
+    /// Spanning tree port priority.
+    pub priority: Option<u16>,
+    /// Spanning tree path cost.
+    pub cost: Option<u32>,
+    /// Spanning tree port identifier.
+    pub port_id: Option<u16>,
+    /// Whether source addresses are learned on this port.
+    pub learning: Option<bool>,
+    /// Whether unknown unicast traffic is flooded to this port.
+    pub unicast_flood: Option<bool>,
+    /// Whether frames may be sent back out of the port they arrived on.
+    pub hairpin_mode: Option<bool>,
+    /// Whether spanning tree BPDUs received on this port disable it.
+    pub bpdu_guard: Option<bool>,
+    /// Whether the port is isolated from other isolated ports.
+    pub isolated: Option<bool>,
+}
+
+/// A bridge with its spanning tree settings and ports.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bridge {
+    /// Index of the bridge interface.
+    pub index: u32,
+    /// Name of the bridge interface.
+    pub name: String,
+    /// Spanning tree mode.
+    pub stp_state: StpState,
+    /// Spanning tree bridge priority.
+    pub priority: Option<u16>,
+    /// Identifier of this bridge.
+    pub bridge_id: Option<BridgeId>,
+    /// Identifier of the root bridge.
+    pub root_id: Option<BridgeId>,
+    /// Port identifier of the root port, `0` when this bridge is the root.
+    pub root_port: Option<u16>,
+    /// Path cost to the root bridge.
+    pub root_path_cost: Option<u32>,
+    /// Spanning tree forward delay.
+    pub forward_delay: Option<Duration>,
+    /// Spanning tree hello time.
+    pub hello_time: Option<Duration>,
+    /// Spanning tree maximum message age.
+    pub max_age: Option<Duration>,
+    /// Time after which dynamic forwarding database entries expire.
+    pub ageing_time: Option<Duration>,
+    /// Whether the bridge filters traffic by VLAN.
+    pub vlan_filtering: bool,
+    /// Tag protocol used when VLAN filtering is enabled.
+    pub vlan_protocol: Option<VlanProtocol>,
+    /// VLAN assigned to untagged traffic on new ports.
+    pub default_pvid: Option<u16>,
+    /// Whether IGMP and MLD snooping is enabled.
+    pub multicast_snooping: Option<bool>,
+    /// Ports of the bridge, ordered by index.
+    pub ports: Vec<BridgePort>,
+}
+
+impl Bridge {
+    /// Returns `true` when this bridge is the spanning tree root.
+    pub fn is_root(&self) -> bool {
+        self.bridge_id.is_some() && self.bridge_id == self.root_id
+    }
+    /// Returns the port with the given interface index.
+    pub fn port(&self, index: u32) -> Option<&BridgePort> {
+        self.ports.iter().find(|port| port.index == index)
+    }
+}
+
+/// How an entry entered a forwarding database.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FdbState {
+    /// Address of the bridge or of one of its ports (`permanent`).
+    Permanent,
+    /// Entry added by configuration (`static`).
+    Static,
+    /// Entry learned from traffic, which expires after the ageing time.
+    Dynamic,
+}
+
+/// An entry of a bridge or VXLAN forwarding database.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FdbEntry {
+    /// Destination link-layer address.
+    pub mac_addr: MacAddr,
+    /// Index of the port the address is reached through.
+    pub if_index: u32,
+    /// Name of the port, when it could be resolved.
+    pub if_name: Option<String>,
+    /// Index of the bridge that owns the entry, `None` for entries of the port itself.
+    pub master_index: Option<u32>,
+    /// VLAN the entry applies to.
+    pub vlan: Option<u16>,
+    /// How the entry was added.
+    pub state: FdbState,
+    /// Remote tunnel endpoint, for VXLAN entries.
+    pub remote: Option<IpAddr>,
+    /// Virtual network identifier, for VXLAN entries.
+    pub vni: Option<u32>,
+    /// Whether the entry was learned by a switch driver or an external controller.
+    pub extern_learned: bool,
+    /// Whether the entry is offloaded to hardware.
+    pub offloaded: bool,
+    /// Whether the entry is prevented from moving to another port.
+    pub sticky: bool,
+    /// Time elapsed since the entry was last updated.
+    pub updated: Option<Duration>,
+}
+
+/// Multicast group of a multicast database entry.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MdbGroup {
+    /// IPv4 or IPv6 multicast group.
+    Ip(IpAddr),
+    /// Link-layer multicast group.
+    Mac(MacAddr),
+}
+
+/// An entry of a bridge multicast database.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MdbEntry {
+    /// Index of the bridge.
+    pub bridge_index: u32,
+    /// Index of the port with members of the group.
+    pub port_index: u32,
+    /// Multicast group.
+    pub group: MdbGroup,
+    /// VLAN the entry applies to.
+    pub vlan: Option<u16>,
+    /// Whether the entry was configured statically.
+    pub permanent: bool,
+    /// Whether the entry is offloaded to hardware.
+    pub offloaded: bool,
+    /// Time left before the membership expires.
+    pub timer: Option<Duration>,
+}
+
+/// Returns every bridge with its spanning tree settings and ports.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the netlink dump fails, and
+/// with `Error::Unsupported` on platforms other than Linux.
+pub fn bridges() -> Result<Vec<Bridge>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::bridge::bridges().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+/// Returns the forwarding database entries of every bridge and VXLAN device, like
+/// `bridge fdb show`.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the netlink dump fails, and
+/// with `Error::Unsupported` on platforms other than Linux.
+pub fn bridge_fdb() -> Result<Vec<FdbEntry>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::bridge::fdb().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+/// Returns the multicast database entries of every bridge, like `bridge mdb show`.
+///
+/// Entries exist only when multicast snooping is enabled on the bridge.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the netlink dump fails, and
+/// with `Error::Unsupported` on platforms other than Linux.
+pub fn bridge_mdb() -> Result<Vec<MdbEntry>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::bridge::mdb().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BridgeId;
+    use crate::net::mac::MacAddr;
+
+    #[test]
+    fn formats_bridge_id() {
+        let id = BridgeId {
+            priority: 0x8000,
+            mac_addr: MacAddr::new(0x02, 0x42, 0xac, 0x11, 0x00, 0x01),
+        };
+        assert_eq!(id.to_string(), "8000.0242ac110001");
+    }
+}
@@ -4,6 +4,11 @@ use serde::{Deserialize, Serialize};
 use super::mac::MacAddr;
 use std::net::{Ipv4Addr, Ipv6Addr};
 
+#[cfg(feature = "gateway")]
+use crate::route::types::IpFamily;
+#[cfg(feature = "gateway")]
+use std::net::IpAddr;
+
 /// Address information for a related network device.
 ///
 /// This type is currently used for devices associated with an interface, such as a default
@@ -31,3 +36,63 @@ impl NetworkDevice {
         }
     }
 }
+
+/// A default gateway reachable through an interface.
+///
+/// Unlike `NetworkDevice`, each entry describes a single router address together with the
+/// route that points to it, so IPv4 and IPv6 routers keep their own link-layer addresses.
+#[cfg(feature = "gateway")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Gateway {
+    /// Address family of the default route.
+    pub family: IpFamily,
+    /// Address of the router.
+    pub ip_addr: IpAddr,
+    /// Link-layer address of the router, when it could be resolved from the neighbour table.
+    pub mac_addr: Option<MacAddr>,
+    /// Metric (priority) of the default route. Lower values are preferred.
+    ///
+    /// This field is `None` when the platform does not report route metrics.
+    pub metric: Option<u32>,
+    /// Routing table holding the default route.
+    ///
+    /// This field is `None` on platforms without multiple routing tables.
+    pub table: Option<u32>,
+}
+
+#[cfg(feature = "gateway")]
+impl Gateway {
+    /// Creates a gateway entry for `ip_addr` without route metadata.
+    pub fn new(ip_addr: IpAddr) -> Gateway {
+        Gateway {
+            family: match ip_addr {
+                IpAddr::V4(_) => IpFamily::V4,
+                IpAddr::V6(_) => IpFamily::V6,
+            },
+            ip_addr,
+            mac_addr: None,
+            metric: None,
+            table: None,
+        }
+    }
+}
+
+/// Expands a combined gateway record into per-address entries.
+///
+/// Used on platforms that only report which routers an interface uses. The shared MAC address
+/// is kept when it was resolved.
+#[cfg(feature = "gateway")]
+pub(crate) fn gateways_from_device(device: &NetworkDevice) -> Vec<Gateway> {
+    let mac_addr = (device.mac_addr != MacAddr::zero()).then_some(device.mac_addr);
+    device
+        .ipv4
+        .iter()
+        .map(|ip| IpAddr::V4(*ip))
+        .chain(device.ipv6.iter().map(|ip| IpAddr::V6(*ip)))
+        .map(|ip| Gateway {
+            mac_addr,
+            ..Gateway::new(ip)
+        })
+        .collect()
+}
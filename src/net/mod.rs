@@ -1,4 +1,10 @@
+pub mod bond;
+pub mod bridge;
 pub mod db;
 pub mod device;
 pub mod ip;
 pub mod mac;
+#[cfg(feature = "gateway")]
+pub mod neighbour;
+pub mod veth;
+pub mod wireguard;
@@ -0,0 +1,147 @@
+use crate::error::Error;
+use crate::net::mac::MacAddr;
+use std::fmt;
+use std::net::IpAddr;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Reachability state of a neighbour cache entry.
+///
+/// This enum models the Linux `NUD_*` states shared by ARP and NDP entries.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NeighbourState {
+    /// Address resolution is in progress.
+    Incomplete,
+    /// The neighbour was recently confirmed reachable.
+    Reachable,
+    /// The entry is valid but has not been confirmed recently.
+    Stale,
+    /// The entry is stale and a confirmation is pending.
+    Delay,
+    /// The neighbour is being actively probed.
+    Probe,
+    /// Address resolution failed.
+    Failed,
+    /// The link does not need address resolution.
+    NoArp,
+    /// The entry was configured statically.
+    Permanent,
+    /// The entry has no state.
+    None,
+    /// Unrecognized state value.
+    Other(u16),
+}
+
+impl NeighbourState {
+    /// Returns the lowercase representation used by `ip neigh`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NeighbourState::Incomplete => "incomplete",
+            NeighbourState::Reachable => "reachable",
+            NeighbourState::Stale => "stale",
+            NeighbourState::Delay => "delay",
+            NeighbourState::Probe => "probe",
+            NeighbourState::Failed => "failed",
+            NeighbourState::NoArp => "noarp",
+            NeighbourState::Permanent => "permanent",
+            NeighbourState::None => "none",
+            NeighbourState::Other(_) => "other",
+        }
+    }
+
+    /// Returns `true` when the entry holds a usable link-layer address.
+    pub fn is_valid(&self) -> bool {
+        matches!(
+            self,
+            NeighbourState::Reachable
+                | NeighbourState::Stale
+                | NeighbourState::Delay
+                | NeighbourState::Probe
+                | NeighbourState::NoArp
+                | NeighbourState::Permanent
+        )
+    }
+}
+
+impl From<u16> for NeighbourState {
+    fn from(v: u16) -> Self {
+        match v {
+            0x01 => NeighbourState::Incomplete,
+            0x02 => NeighbourState::Reachable,
+            0x04 => NeighbourState::Stale,
+            0x08 => NeighbourState::Delay,
+            0x10 => NeighbourState::Probe,
+            0x20 => NeighbourState::Failed,
+            0x40 => NeighbourState::NoArp,
+            0x80 => NeighbourState::Permanent,
+            0x00 => NeighbourState::None,
+            _ => NeighbourState::Other(v),
+        }
+    }
+}
+
+impl fmt::Display for NeighbourState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// An entry of the ARP (IPv4) or NDP (IPv6) neighbour cache.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Neighbour {
+    /// Protocol address of the neighbour.
+    pub ip_addr: IpAddr,
+    /// Link-layer address of the neighbour.
+    ///
+    /// This field is `None` for unresolved entries and for links whose hardware
+    /// addresses are not 6 bytes long.
+    pub mac_addr: Option<MacAddr>,
+    /// Index of the interface the entry belongs to.
+    pub if_index: u32,
+    /// Name of the interface the entry belongs to, when it could be resolved.
+    pub if_name: Option<String>,
+    /// Reachability state of the entry.
+    pub state: NeighbourState,
+    /// Whether the neighbour announced itself as an IPv6 router.
+    pub is_router: bool,
+    /// Time elapsed since the neighbour was last confirmed reachable.
+    pub confirmed: Option<Duration>,
+    /// Time elapsed since the entry was last used.
+    pub used: Option<Duration>,
+    /// Time elapsed since the entry was last updated.
+    pub updated: Option<Duration>,
+}
+
+/// Returns every entry of the IPv4 and IPv6 neighbour caches.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the neighbour dump fails,
+/// and with `Error::Unsupported` on platforms where the neighbour cache cannot be read.
+pub fn neighbours() -> Result<Vec<Neighbour>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::neighbour::neighbours().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NeighbourState;
+
+    #[test]
+    fn maps_nud_values() {
+        assert_eq!(NeighbourState::from(0x02), NeighbourState::Reachable);
+        assert_eq!(NeighbourState::from(0x40), NeighbourState::NoArp);
+        assert_eq!(NeighbourState::from(0x03), NeighbourState::Other(0x03));
+        assert!(NeighbourState::Stale.is_valid());
+        assert!(!NeighbourState::Failed.is_valid());
+        assert!(!NeighbourState::Incomplete.is_valid());
+    }
+}
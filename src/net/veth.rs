@@ -0,0 +1,67 @@
+use crate::error::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A veth interface and the other end of its pair.
+///
+/// The peer often lives in another network namespace, such as a container, in which case its
+/// index is only meaningful inside that namespace.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Veth {
+    /// Index of the veth interface.
+    pub index: u32,
+    /// Name of the veth interface.
+    pub name: String,
+    /// Index of the peer in its network namespace.
+    pub peer_index: u32,
+    /// Id of the peer's network namespace, as listed by `ip netns list-id`.
+    ///
+    /// `None` when the peer is in the current namespace.
+    pub peer_netns_id: Option<i32>,
+    /// Name of the peer, when it could be resolved.
+    ///
+    /// Resolving a peer in another namespace requires Linux 4.15 or later and
+    /// `CAP_NET_ADMIN` in that namespace.
+    pub peer_name: Option<String>,
+}
+
+impl Veth {
+    /// Returns `true` when the peer is in the current network namespace.
+    pub fn is_peer_local(&self) -> bool {
+        self.peer_netns_id.is_none()
+    }
+}
+
+/// Returns every veth interface with its peer.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the link dump fails, and
+/// with `Error::Unsupported` on platforms other than Linux.
+pub fn veths() -> Result<Vec<Veth>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::veth::veths().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+/// Returns the veth interface with the given index and its peer, or `None` when the interface
+/// does not exist or is not a veth.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the link lookup fails, and
+/// with `Error::Unsupported` on platforms other than Linux.
+pub fn veth_by_index(index: u32) -> Result<Option<Veth>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::veth::veth_by_index(index).map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = index;
+        Err(Error::Unsupported)
+    }
+}
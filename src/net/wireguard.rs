@@ -0,0 +1,146 @@
+use crate::error::Error;
+use ipnet::IpNet;
+use std::fmt;
+use std::net::SocketAddr;
+use std::time::{Duration, SystemTime};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A Curve25519 public key of a WireGuard interface or peer.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WireGuardKey([u8; 32]);
+
+impl WireGuardKey {
+    /// Creates a key from its raw bytes.
+    pub fn new(bytes: [u8; 32]) -> WireGuardKey {
+        WireGuardKey(bytes)
+    }
+    /// Returns the raw bytes of the key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for WireGuardKey {
+    /// Formats the key in base64, as printed by `wg show`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        for chunk in self.0.chunks(3) {
+            let b = [
+                chunk[0],
+                *chunk.get(1).unwrap_or(&0),
+                *chunk.get(2).unwrap_or(&0),
+            ];
+            let n = u32::from(b[0]) << 16 | u32::from(b[1]) << 8 | u32::from(b[2]);
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    let c = ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize];
+                    write!(f, "{}", c as char)?;
+                } else {
+                    write!(f, "=")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A peer of a WireGuard interface.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WireGuardPeer {
+    /// Public key of the peer.
+    pub public_key: WireGuardKey,
+    /// Address the peer was last reached at or was configured with.
+    pub endpoint: Option<SocketAddr>,
+    /// Networks routed to the peer and accepted from it.
+    pub allowed_ips: Vec<IpNet>,
+    /// Time of the most recent completed handshake, `None` when no handshake has completed.
+    pub latest_handshake: Option<SystemTime>,
+    /// Bytes received from the peer.
+    pub rx_bytes: u64,
+    /// Bytes sent to the peer.
+    pub tx_bytes: u64,
+    /// Interval of keepalive packets, `None` when persistent keepalive is off.
+    pub persistent_keepalive: Option<Duration>,
+}
+
+/// A WireGuard interface with its configuration and peers.
+///
+/// The private key and preshared keys are never read.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WireGuardDevice {
+    /// Index of the interface.
+    pub index: u32,
+    /// Name of the interface.
+    pub name: String,
+    /// Public key of the interface, `None` when no private key is set.
+    pub public_key: Option<WireGuardKey>,
+    /// UDP port the interface listens on, `None` when not yet assigned.
+    pub listen_port: Option<u16>,
+    /// Mark applied to outgoing packets, `None` when unset.
+    pub fwmark: Option<u32>,
+    /// Peers of the interface.
+    pub peers: Vec<WireGuardPeer>,
+}
+
+impl WireGuardDevice {
+    /// Returns the peer with the given public key.
+    pub fn peer(&self, public_key: &WireGuardKey) -> Option<&WireGuardPeer> {
+        self.peers
+            .iter()
+            .find(|peer| peer.public_key == *public_key)
+    }
+}
+
+/// Returns every WireGuard interface with its configuration and peers, like `wg show`.
+///
+/// On Linux the details come from the `wireguard` generic netlink family, which requires
+/// `CAP_NET_ADMIN`. Without it `Error::PermissionDenied` is returned. Other netlink failures
+/// return `Error::Netlink`, and other platforms return `Error::Unsupported`.
+pub fn wireguard_devices() -> Result<Vec<WireGuardDevice>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::wireguard::devices().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+/// Returns the WireGuard interface with the given index, or `None` when the interface does not
+/// exist or is not a WireGuard interface.
+///
+/// Requires `CAP_NET_ADMIN` on Linux and fails like [`wireguard_devices`].
+pub fn wireguard_device_by_index(index: u32) -> Result<Option<WireGuardDevice>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::wireguard::device_by_index(index).map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = index;
+        Err(Error::Unsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WireGuardKey;
+
+    #[test]
+    fn formats_key_as_base64() {
+        let mut bytes = [0u8; 32];
+        bytes[..3].copy_from_slice(b"Man");
+        bytes[31] = 0xff;
+        let key = WireGuardKey::new(bytes).to_string();
+        assert_eq!(key.len(), 44);
+        assert!(key.starts_with("TWFu"));
+        assert!(key.ends_with("AP8="));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::stats::counters::InterfaceStats;
+use crate::stats::counters::{CounterWidth, InterfaceStats};
 use jni::JavaVM;
 use jni::objects::{JByteArray, JObject, JObjectArray, JString, JValue};
 use std::collections::{HashMap, HashSet};
@@ -45,6 +45,8 @@ pub(crate) fn get_interface_stats(name: &str) -> Option<InterfaceStats> {
             rx_bytes: rx_bytes as u64,
             tx_bytes: tx_bytes as u64,
             timestamp: Some(SystemTime::now()),
+            counter_width: CounterWidth::Bits64,
+            extended: None,
         })
     })
 }
@@ -280,6 +282,8 @@ fn get_traffic_stats(env: &mut jni::AttachGuard<'_>, if_name: &str) -> Option<In
         rx_bytes: rx_bytes as u64,
         tx_bytes: tx_bytes as u64,
         timestamp: Some(SystemTime::now()),
+        counter_width: CounterWidth::Bits64,
+        extended: None,
     })
 }
 
@@ -4,6 +4,7 @@ use crate::interface::state::OperState;
 use crate::interface::types::InterfaceType;
 use crate::ipnet::{Ipv4Net, Ipv6Net};
 use crate::net::mac::MacAddr;
+use std::io;
 use std::net::{Ipv4Addr, Ipv6Addr};
 
 #[cfg(feature = "gateway")]
@@ -155,6 +156,15 @@ fn finalize_interface(iface: &mut Interface) {
 }
 
 pub fn interfaces() -> Vec<Interface> {
+    collect(false).unwrap_or_default()
+}
+
+/// Like `interfaces`, but fails instead of falling back when netlink is unavailable.
+pub fn try_interfaces() -> io::Result<Vec<Interface>> {
+    collect(true)
+}
+
+fn collect(strict: bool) -> io::Result<Vec<Interface>> {
     let mut ifaces: Vec<Interface> = Vec::new();
 
     match netlink::collect_interfaces() {
@@ -167,6 +177,8 @@ pub fn interfaces() -> Vec<Interface> {
                     friendly_name: None,
                     description: None,
                     if_type: r.if_type,
+                    link_kind: None,
+                    tunnel: None,
                     mac_addr: r.mac.map(MacAddr::from_octets),
                     ipv4: Vec::new(),
                     ipv6: Vec::new(),
@@ -183,10 +195,16 @@ pub fn interfaces() -> Vec<Interface> {
                     #[cfg(feature = "gateway")]
                     gateway: None,
                     #[cfg(feature = "gateway")]
+                    gateways: Vec::new(),
+                    #[cfg(feature = "gateway")]
                     dns_servers: Vec::new(),
                     mtu: r.mtu,
                     #[cfg(feature = "gateway")]
                     default: false,
+                    #[cfg(feature = "gateway")]
+                    default_v4: false,
+                    #[cfg(feature = "gateway")]
+                    default_v6: false,
                 };
 
                 for (a, p) in r.ipv4 {
@@ -209,6 +227,7 @@ pub fn interfaces() -> Vec<Interface> {
                 ifaces.push(iface);
             }
         }
+        Err(e) if strict => return Err(e),
         Err(_) => {
             // fallback: unix ifaddrs
             ifaces = unix_interfaces();
@@ -262,7 +281,7 @@ pub fn interfaces() -> Vec<Interface> {
         }
     }
 
-    ifaces
+    Ok(ifaces)
 }
 
 #[cfg(test)]
@@ -8,7 +8,6 @@ use netlink_packet_route::{
 };
 use netlink_sys::{Socket, protocols::NETLINK_ROUTE};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
-use std::time::SystemTime;
 use std::{collections::HashMap, io};
 
 #[cfg(feature = "gateway")]
@@ -230,29 +229,6 @@ fn if_type_from_link(link: &LinkMessage, _name: &str) -> InterfaceType {
     InterfaceType::try_from(arphrd).unwrap_or(InterfaceType::UnknownWithValue(arphrd))
 }
 
-fn stats_from_link(link: &LinkMessage) -> Option<InterfaceStats> {
-    for nla in &link.attributes {
-        match nla {
-            LinkAttribute::Stats64(s) => {
-                return Some(InterfaceStats {
-                    rx_bytes: s.rx_bytes,
-                    tx_bytes: s.tx_bytes,
-                    timestamp: Some(SystemTime::now()),
-                });
-            }
-            LinkAttribute::Stats(s) => {
-                return Some(InterfaceStats {
-                    rx_bytes: s.rx_bytes as u64,
-                    tx_bytes: s.tx_bytes as u64,
-                    timestamp: Some(SystemTime::now()),
-                });
-            }
-            _ => {}
-        }
-    }
-    None
-}
-
 pub fn get_flags_by_name(name: &str) -> io::Result<Option<u32>> {
     let links = dump_links()?;
     for l in links {
@@ -291,7 +267,7 @@ pub fn collect_interfaces() -> io::Result<Vec<IfRow>> {
         let flags = l.header.flags.bits();
         let mtu_nl = mtu_from_link(&l);
         let if_type = if_type_from_link(&l, &name);
-        let stats = stats_from_link(&l);
+        let stats = crate::os::linux::stats::stats_from_link(&l);
 
         base.insert(
             idx,
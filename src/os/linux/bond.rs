@@ -0,0 +1,541 @@
+use super::netlink;
+use crate::net::bond::{AdAggregator, Bond, BondMode, BondSlave, LacpRate, MiiStatus, SlaveState};
+use crate::net::mac::MacAddr;
+use netlink_packet_core::Nla;
+use netlink_packet_route::link::{
+    self, BondAdInfo, InfoBond, InfoBondPort, InfoData, InfoKind, InfoPortData, LinkAttribute,
+    LinkInfo, LinkMessage,
+};
+use std::ffi::CString;
+use std::fs;
+use std::io;
+use std::time::Duration;
+
+const PROC_NET_BONDING: &str = "/proc/net/bonding";
+
+// Slave attributes that `InfoBondPort` does not decode, from linux/if_link.h.
+const IFLA_BOND_SLAVE_AD_AGGREGATOR_ID: u16 = 6;
+const IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE: u16 = 7;
+const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: u16 = 8;
+
+pub fn bonds() -> io::Result<Vec<Bond>> {
+    let links = match netlink::dump_links() {
+        Ok(links) => links,
+        Err(e) => return proc_bonds().map_err(|_| e),
+    };
+
+    let mut bonds = Vec::new();
+    for link in &links {
+        match bond_from_link(link) {
+            Some(Some(bond)) => bonds.push(bond),
+            // The bond kind is known but its attributes are missing.
+            Some(None) => {
+                if let Some(name) = netlink::name_from_link(link)
+                    && let Some(bond) = proc_bond(&name)
+                {
+                    bonds.push(bond);
+                }
+            }
+            None => {}
+        }
+    }
+    for link in &links {
+        if let Some((master, slave)) = slave_from_link(link)
+            && let Some(bond) = bonds.iter_mut().find(|b| b.index == master)
+            && bond.slave(slave.index).is_none()
+        {
+            bond.slaves.push(slave);
+        }
+    }
+    bonds.sort_by_key(|b| b.index);
+    for bond in &mut bonds {
+        bond.slaves.sort_by_key(|s| s.index);
+    }
+    Ok(bonds)
+}
+
+/// Returns `None` for links that are not bonds, and `Some(None)` for bonds whose
+/// `IFLA_INFO_DATA` is missing.
+fn bond_from_link(link: &LinkMessage) -> Option<Option<Bond>> {
+    let (InfoKind::Bond, data) = super::linkinfo::link_info(link)? else {
+        return None;
+    };
+    let Some(InfoData::Bond(infos)) = data else {
+        return Some(None);
+    };
+
+    let mut bond = Bond {
+        index: link.header.index,
+        name: netlink::name_from_link(link).unwrap_or_default(),
+        mode: BondMode::BalanceRr,
+        active_slave: None,
+        primary: None,
+        miimon: None,
+        up_delay: None,
+        down_delay: None,
+        xmit_hash_policy: None,
+        lacp_rate: None,
+        min_links: None,
+        aggregator: None,
+        slaves: Vec::new(),
+    };
+    let millis = |v: u32| (v != 0).then(|| Duration::from_millis(v.into()));
+    for info in infos {
+        match info {
+            InfoBond::Mode(mode) => bond.mode = bond_mode(*mode),
+            InfoBond::ActivePort(index) => bond.active_slave = Some(*index),
+            InfoBond::Primary(index) => bond.primary = Some(*index),
+            InfoBond::MiiMon(v) => bond.miimon = millis(*v),
+            InfoBond::UpDelay(v) => bond.up_delay = millis(*v),
+            InfoBond::DownDelay(v) => bond.down_delay = millis(*v),
+            InfoBond::XmitHashPolicy(policy) => bond.xmit_hash_policy = Some(policy.to_string()),
+            InfoBond::AdLacpRate(rate) => {
+                bond.lacp_rate = match rate {
+                    link::BondLacpRate::Slow => Some(LacpRate::Slow),
+                    link::BondLacpRate::Fast => Some(LacpRate::Fast),
+                    _ => None,
+                }
+            }
+            InfoBond::MinLinks(v) => bond.min_links = Some(*v),
+            InfoBond::AdInfo(ad) => bond.aggregator = ad_aggregator(ad),
+            _ => {}
+        }
+    }
+    Some(Some(bond))
+}
+
+fn bond_mode(mode: link::BondMode) -> BondMode {
+    match mode {
+        link::BondMode::BalanceRr => BondMode::BalanceRr,
+        link::BondMode::ActiveBackup => BondMode::ActiveBackup,
+        link::BondMode::BalanceXor => BondMode::BalanceXor,
+        link::BondMode::Broadcast => BondMode::Broadcast,
+        link::BondMode::Ieee8023Ad => BondMode::Ieee8023Ad,
+        link::BondMode::BalanceTlb => BondMode::BalanceTlb,
+        link::BondMode::BalanceAlb => BondMode::BalanceAlb,
+        other => BondMode::Other(other.into()),
+    }
+}
+
+fn ad_aggregator(infos: &[BondAdInfo]) -> Option<AdAggregator> {
+    let mut agg = AdAggregator {
+        id: 0,
+        num_ports: 0,
+        actor_key: 0,
+        partner_key: 0,
+        partner_mac: MacAddr::zero(),
+    };
+    let mut found = false;
+    for info in infos {
+        match info {
+            BondAdInfo::Aggregator(v) => {
+                agg.id = *v;
+                found = true;
+            }
+            BondAdInfo::NumPorts(v) => agg.num_ports = *v,
+            BondAdInfo::ActorKey(v) => agg.actor_key = *v,
+            BondAdInfo::PartnerKey(v) => agg.partner_key = *v,
+            BondAdInfo::PartnerMac(mac) => agg.partner_mac = MacAddr::from_octets(*mac),
+            _ => {}
+        }
+    }
+    found.then_some(agg)
+}
+
+/// Returns the index of the bond a link is enslaved to, with the slave details.
+fn slave_from_link(link: &LinkMessage) -> Option<(u32, BondSlave)> {
+    let mut master = None;
+    let mut infos: &[InfoBondPort] = &[];
+    for nla in &link.attributes {
+        match nla {
+            LinkAttribute::Controller(index) => master = Some(*index),
+            LinkAttribute::LinkInfo(link_infos) => {
+                for info in link_infos {
+                    if let LinkInfo::PortData(InfoPortData::BondPort(port)) = info {
+                        infos = port;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    let master = master?;
+    if infos.is_empty() {
+        return None;
+    }
+
+    let mut slave = BondSlave {
+        index: link.header.index,
+        name: netlink::name_from_link(link).unwrap_or_default(),
+        state: None,
+        mii_status: MiiStatus::Down,
+        link_failure_count: 0,
+        perm_mac_addr: None,
+        queue_id: None,
+        aggregator_id: None,
+        actor_port_state: None,
+        partner_port_state: None,
+    };
+    for info in infos {
+        match info {
+            InfoBondPort::BondPortState(state) => {
+                slave.state = Some(match state {
+                    link::BondPortState::Active => SlaveState::Active,
+                    link::BondPortState::Backup => SlaveState::Backup,
+                    other => SlaveState::Other((*other).into()),
+                })
+            }
+            InfoBondPort::MiiStatus(status) => {
+                slave.mii_status = match status {
+                    link::MiiStatus::Up => MiiStatus::Up,
+                    link::MiiStatus::GoingDown => MiiStatus::GoingDown,
+                    link::MiiStatus::Down => MiiStatus::Down,
+                    link::MiiStatus::GoingBack => MiiStatus::GoingBack,
+                    other => MiiStatus::Other((*other).into()),
+                }
+            }
+            InfoBondPort::LinkFailureCount(v) => slave.link_failure_count = *v,
+            InfoBondPort::PermHwaddr(bytes) => {
+                slave.perm_mac_addr = <[u8; 6]>::try_from(bytes.as_slice())
+                    .ok()
+                    .map(MacAddr::from_octets);
+            }
+            InfoBondPort::QueueId(v) => slave.queue_id = Some(*v),
+            InfoBondPort::Other(nla) => {
+                let mut value = [0u8; 2];
+                match (nla.kind(), nla.value_len()) {
+                    (IFLA_BOND_SLAVE_AD_AGGREGATOR_ID, 2) => {
+                        nla.emit_value(&mut value);
+                        slave.aggregator_id = Some(u16::from_ne_bytes(value));
+                    }
+                    (IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE, 1) => {
+                        nla.emit_value(&mut value[..1]);
+                        slave.actor_port_state = Some(value[0]);
+                    }
+                    (IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE, 2) => {
+                        nla.emit_value(&mut value);
+                        slave.partner_port_state = Some(u16::from_ne_bytes(value));
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    Some((master, slave))
+}
+
+fn if_index(name: &str) -> u32 {
+    CString::new(name)
+        .map(|name| unsafe { libc::if_nametoindex(name.as_ptr()) })
+        .unwrap_or(0)
+}
+
+fn proc_bonds() -> io::Result<Vec<Bond>> {
+    let mut bonds: Vec<Bond> = fs::read_dir(PROC_NET_BONDING)?
+        .filter_map(|entry| proc_bond(entry.ok()?.file_name().to_str()?))
+        .collect();
+    bonds.sort_by_key(|b| b.index);
+    Ok(bonds)
+}
+
+fn proc_bond(name: &str) -> Option<Bond> {
+    let text = fs::read_to_string(format!("{}/{}", PROC_NET_BONDING, name)).ok()?;
+    Some(parse_proc_bond(name, &text, if_index))
+}
+
+fn mii_status(s: &str) -> MiiStatus {
+    match s {
+        "up" => MiiStatus::Up,
+        "going down" => MiiStatus::GoingDown,
+        "going back" => MiiStatus::GoingBack,
+        _ => MiiStatus::Down,
+    }
+}
+
+fn proc_bond_mode(s: &str) -> BondMode {
+    if s.contains("802.3ad") {
+        BondMode::Ieee8023Ad
+    } else if s.contains("(round-robin)") {
+        BondMode::BalanceRr
+    } else if s.contains("(active-backup)") {
+        BondMode::ActiveBackup
+    } else if s.contains("(xor)") {
+        BondMode::BalanceXor
+    } else if s.contains("(broadcast)") {
+        BondMode::Broadcast
+    } else if s.starts_with("transmit load balancing") {
+        BondMode::BalanceTlb
+    } else if s.starts_with("adaptive load balancing") {
+        BondMode::BalanceAlb
+    } else {
+        BondMode::Other(u8::MAX)
+    }
+}
+
+/// Parses a `/proc/net/bonding/<name>` report. `index_of` resolves interface names to indexes.
+fn parse_proc_bond(name: &str, text: &str, index_of: impl Fn(&str) -> u32) -> Bond {
+    let mut bond = Bond {
+        index: index_of(name),
+        name: name.to_string(),
+        mode: BondMode::BalanceRr,
+        active_slave: None,
+        primary: None,
+        miimon: None,
+        up_delay: None,
+        down_delay: None,
+        xmit_hash_policy: None,
+        lacp_rate: None,
+        min_links: None,
+        aggregator: None,
+        slaves: Vec::new(),
+    };
+    let millis = |v: &str| {
+        v.parse::<u64>()
+            .ok()
+            .filter(|v| *v != 0)
+            .map(Duration::from_millis)
+    };
+    let mut active_name = None;
+    let mut in_aggregator = false;
+    // The LACPDU details of a slave list the actor first and then the partner.
+    let mut partner_details = false;
+
+    for line in text.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+
+        if key == "Slave Interface" {
+            bond.slaves.push(BondSlave {
+                index: index_of(value),
+                name: value.to_string(),
+                state: None,
+                mii_status: MiiStatus::Down,
+                link_failure_count: 0,
+                perm_mac_addr: None,
+                queue_id: None,
+                aggregator_id: None,
+                actor_port_state: None,
+                partner_port_state: None,
+            });
+            in_aggregator = false;
+            partner_details = false;
+            continue;
+        }
+
+        if let Some(slave) = bond.slaves.last_mut() {
+            match key {
+                "MII Status" => slave.mii_status = mii_status(value),
+                "Link Failure Count" => slave.link_failure_count = value.parse().unwrap_or(0),
+                "Permanent HW addr" => slave.perm_mac_addr = value.parse().ok(),
+                "Slave queue ID" => slave.queue_id = value.parse().ok(),
+                "Aggregator ID" => slave.aggregator_id = value.parse().ok(),
+                "details partner lacp pdu" => partner_details = true,
+                "port state" if partner_details => slave.partner_port_state = value.parse().ok(),
+                "port state" => slave.actor_port_state = value.parse().ok(),
+                _ => {}
+            }
+            continue;
+        }
+
+        if in_aggregator {
+            let agg = bond.aggregator.get_or_insert(AdAggregator {
+                id: 0,
+                num_ports: 0,
+                actor_key: 0,
+                partner_key: 0,
+                partner_mac: MacAddr::zero(),
+            });
+            match key {
+                "Aggregator ID" => agg.id = value.parse().unwrap_or(0),
+                "Number of ports" => agg.num_ports = value.parse().unwrap_or(0),
+                "Actor Key" => agg.actor_key = value.parse().unwrap_or(0),
+                "Partner Key" => agg.partner_key = value.parse().unwrap_or(0),
+                "Partner Mac Address" => agg.partner_mac = value.parse().unwrap_or(MacAddr::zero()),
+                _ => {}
+            }
+            continue;
+        }
+
+        match key {
+            "Bonding Mode" => bond.mode = proc_bond_mode(value),
+            "Currently Active Slave" if value != "None" => active_name = Some(value.to_string()),
+            "Primary Slave" if value != "None" => {
+                // Reported as `eth0` or `eth0 (primary_reselect always)`.
+                let primary = value.split_whitespace().next().unwrap_or(value);
+                bond.primary = Some(index_of(primary));
+            }
+            "MII Polling Interval (ms)" => bond.miimon = millis(value),
+            "Up Delay (ms)" => bond.up_delay = millis(value),
+            "Down Delay (ms)" => bond.down_delay = millis(value),
+            "Transmit Hash Policy" => {
+                // Reported as `layer3+4 (1)`.
+                bond.xmit_hash_policy = value.split_whitespace().next().map(str::to_string);
+            }
+            "LACP rate" => {
+                bond.lacp_rate = match value {
+                    "slow" => Some(LacpRate::Slow),
+                    "fast" => Some(LacpRate::Fast),
+                    _ => None,
+                }
+            }
+            "Min links" => bond.min_links = value.parse().ok(),
+            "Active Aggregator Info" => in_aggregator = true,
+            _ => {}
+        }
+    }
+
+    if let Some(active) = active_name {
+        for slave in &mut bond.slaves {
+            slave.state = Some(if slave.name == active {
+                SlaveState::Active
+            } else {
+                SlaveState::Backup
+            });
+        }
+        bond.active_slave = bond
+            .slaves
+            .iter()
+            .find(|s| s.name == active)
+            .map(|s| s.index);
+    }
+    bond.slaves.sort_by_key(|s| s.index);
+    bond
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_proc_bond;
+    use crate::net::bond::{BondMode, LacpRate, MiiStatus, SlaveState};
+    use crate::net::mac::MacAddr;
+    use std::time::Duration;
+
+    fn index_of(name: &str) -> u32 {
+        match name {
+            "bond0" => 4,
+            "eth0" => 2,
+            "eth1" => 3,
+            _ => 0,
+        }
+    }
+
+    const ACTIVE_BACKUP: &str = "\
+Ethernet Channel Bonding Driver: v6.8.0
+
+Bonding Mode: fault-tolerance (active-backup)
+Primary Slave: None
+Currently Active Slave: eth1
+MII Status: up
+MII Polling Interval (ms): 100
+Up Delay (ms): 0
+Down Delay (ms): 200
+
+Slave Interface: eth0
+MII Status: down
+Speed: Unknown
+Duplex: Unknown
+Link Failure Count: 3
+Permanent HW addr: 52:54:00:00:00:01
+Slave queue ID: 0
+
+Slave Interface: eth1
+MII Status: up
+Speed: 1000 Mbps
+Duplex: full
+Link Failure Count: 0
+Permanent HW addr: 52:54:00:00:00:02
+Slave queue ID: 0
+";
+
+    const LACP: &str = "\
+Ethernet Channel Bonding Driver: v6.8.0
+
+Bonding Mode: IEEE 802.3ad Dynamic link aggregation
+Transmit Hash Policy: layer3+4 (1)
+MII Status: up
+MII Polling Interval (ms): 100
+Up Delay (ms): 0
+Down Delay (ms): 0
+
+802.3ad info
+LACP active: on
+LACP rate: fast
+Min links: 1
+Aggregator selection policy (ad_select): stable
+System priority: 65535
+System MAC address: 52:54:00:00:00:01
+Active Aggregator Info:
+	Aggregator ID: 1
+	Number of ports: 2
+	Actor Key: 15
+	Partner Key: 32
+	Partner Mac Address: 00:11:22:33:44:55
+
+Slave Interface: eth0
+MII Status: up
+Speed: 1000 Mbps
+Duplex: full
+Link Failure Count: 0
+Permanent HW addr: 52:54:00:00:00:01
+Slave queue ID: 0
+Aggregator ID: 1
+details actor lacp pdu:
+    system priority: 65535
+    port state: 61
+details partner lacp pdu:
+    system priority: 32768
+    port state: 63
+";
+
+    #[test]
+    fn parses_active_backup_report() {
+        let bond = parse_proc_bond("bond0", ACTIVE_BACKUP, index_of);
+        assert_eq!(bond.index, 4);
+        assert_eq!(bond.mode, BondMode::ActiveBackup);
+        assert_eq!(bond.active_slave, Some(3));
+        assert_eq!(bond.primary, None);
+        assert_eq!(bond.miimon, Some(Duration::from_millis(100)));
+        assert_eq!(
+            (bond.up_delay, bond.down_delay),
+            (None, Some(Duration::from_millis(200)))
+        );
+
+        let eth0 = bond.slave(2).unwrap();
+        assert_eq!(eth0.mii_status, MiiStatus::Down);
+        assert_eq!(eth0.state, Some(SlaveState::Backup));
+        assert_eq!(eth0.link_failure_count, 3);
+        assert_eq!(
+            eth0.perm_mac_addr,
+            Some(MacAddr::new(0x52, 0x54, 0, 0, 0, 0x01))
+        );
+        assert_eq!(bond.slave(3).unwrap().state, Some(SlaveState::Active));
+        assert_eq!(bond.slaves_up().count(), 1);
+    }
+
+    #[test]
+    fn parses_lacp_report() {
+        let bond = parse_proc_bond("bond0", LACP, index_of);
+        assert_eq!(bond.mode, BondMode::Ieee8023Ad);
+        assert_eq!(bond.xmit_hash_policy.as_deref(), Some("layer3+4"));
+        assert_eq!(bond.lacp_rate, Some(LacpRate::Fast));
+        assert_eq!(bond.min_links, Some(1));
+
+        let agg = bond.aggregator.unwrap();
+        assert_eq!((agg.id, agg.num_ports), (1, 2));
+        assert_eq!((agg.actor_key, agg.partner_key), (15, 32));
+        assert_eq!(
+            agg.partner_mac,
+            MacAddr::new(0, 0x11, 0x22, 0x33, 0x44, 0x55)
+        );
+
+        let eth0 = bond.slave(2).unwrap();
+        assert_eq!(eth0.aggregator_id, Some(1));
+        assert_eq!(
+            (eth0.actor_port_state, eth0.partner_port_state),
+            (Some(61), Some(63))
+        );
+        assert_eq!(eth0.state, None);
+    }
+}
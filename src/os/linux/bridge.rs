@@ -0,0 +1,429 @@
+use super::netlink::{self, clock_ticks_to_duration};
+use crate::net::bridge::{
+    Bridge, BridgeId, BridgePort, FdbEntry, FdbState, MdbEntry, MdbGroup, PortState, StpState,
+};
+use crate::net::mac::MacAddr;
+use netlink_packet_core::{NlaBuffer, NlasIterator};
+use netlink_packet_route::AddressFamily;
+use netlink_packet_route::link::{
+    self, InfoBridge, InfoBridgePort, InfoData, InfoKind, InfoPortData, LinkAttribute, LinkInfo,
+    LinkMessage,
+};
+use netlink_packet_route::neighbour::{
+    NeighbourAddress, NeighbourAttribute, NeighbourFlags, NeighbourMessage, NeighbourState,
+};
+use std::collections::HashMap;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+// Attributes of RTM_NEWMDB messages, from linux/if_bridge.h.
+const MDBA_MDB: u16 = 1;
+const MDBA_MDB_ENTRY: u16 = 1;
+const MDBA_MDB_ENTRY_INFO: u16 = 1;
+const MDBA_MDB_EATTR_TIMER: u16 = 1;
+const BR_PORT_MSG_LEN: usize = 8;
+const BR_MDB_ENTRY_LEN: usize = 28;
+const MDB_PERMANENT: u8 = 1;
+const MDB_FLAGS_OFFLOAD: u8 = 0x01;
+const ETH_P_IP: u16 = 0x0800;
+const ETH_P_IPV6: u16 = 0x86dd;
+
+pub fn bridges() -> io::Result<Vec<Bridge>> {
+    let links = netlink::dump_links()?;
+    let mut bridges: Vec<Bridge> = links.iter().filter_map(bridge_from_link).collect();
+    for link in &links {
+        if let Some((master, port)) = port_from_link(link)
+            && let Some(bridge) = bridges.iter_mut().find(|b| b.index == master)
+        {
+            bridge.ports.push(port);
+        }
+    }
+    bridges.sort_by_key(|b| b.index);
+    for bridge in &mut bridges {
+        bridge.ports.sort_by_key(|p| p.index);
+    }
+    Ok(bridges)
+}
+
+pub fn fdb() -> io::Result<Vec<FdbEntry>> {
+    let msgs = netlink::dump_bridge_fdb()?;
+    let names: HashMap<u32, String> = netlink::dump_links()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|link| Some((link.header.index, netlink::name_from_link(link)?)))
+        .collect();
+    Ok(msgs.iter().filter_map(|n| fdb_entry(n, &names)).collect())
+}
+
+pub fn mdb() -> io::Result<Vec<MdbEntry>> {
+    Ok(netlink::dump_mdb()?
+        .iter()
+        .flat_map(|payload| parse_mdb(payload))
+        .collect())
+}
+
+fn bridge_id(id: &link::BridgeId) -> BridgeId {
+    BridgeId {
+        priority: id.priority,
+        mac_addr: MacAddr::from_octets(id.address),
+    }
+}
+
+fn bridge_from_link(link: &LinkMessage) -> Option<Bridge> {
+    let (InfoKind::Bridge, data) = super::linkinfo::link_info(link)? else {
+        return None;
+    };
+    let mut bridge = Bridge {
+        index: link.header.index,
+        name: netlink::name_from_link(link).unwrap_or_default(),
+        stp_state: StpState::Disabled,
+        priority: None,
+        bridge_id: None,
+        root_id: None,
+        root_port: None,
+        root_path_cost: None,
+        forward_delay: None,
+        hello_time: None,
+        max_age: None,
+        ageing_time: None,
+        vlan_filtering: false,
+        vlan_protocol: None,
+        default_pvid: None,
+        multicast_snooping: None,
+        ports: Vec::new(),
+    };
+    let Some(InfoData::Bridge(infos)) = data else {
+        return Some(bridge);
+    };
+    // Bridge timers are reported in clock ticks.
+    let ticks = |v: u32| Some(clock_ticks_to_duration(v.into()));
+    for info in infos {
+        match info {
+            InfoBridge::StpState(state) => {
+                bridge.stp_state = match state {
+                    link::BridgeStpState::Disabled => StpState::Disabled,
+                    link::BridgeStpState::KernelStp => StpState::Kernel,
+                    link::BridgeStpState::UserStp => StpState::User,
+                    other => StpState::Other((*other).into()),
+                }
+            }
+            InfoBridge::Priority(v) => bridge.priority = Some(*v),
+            InfoBridge::BridgeId(id) => bridge.bridge_id = Some(bridge_id(id)),
+            InfoBridge::RootId(id) => bridge.root_id = Some(bridge_id(id)),
+            InfoBridge::RootPort(v) => bridge.root_port = Some(*v),
+            InfoBridge::RootPathCost(v) => bridge.root_path_cost = Some(*v),
+            InfoBridge::ForwardDelay(v) => bridge.forward_delay = ticks(*v),
+            InfoBridge::HelloTime(v) => bridge.hello_time = ticks(*v),
+            InfoBridge::MaxAge(v) => bridge.max_age = ticks(*v),
+            InfoBridge::AgeingTime(v) => bridge.ageing_time = ticks(*v),
+            InfoBridge::VlanFiltering(v) => bridge.vlan_filtering = *v,
+            InfoBridge::VlanProtocol(p) => bridge.vlan_protocol = Some(u16::from(*p).into()),
+            InfoBridge::VlanDefaultPvid(v) => bridge.default_pvid = Some(*v),
+            InfoBridge::MulticastSnooping(v) => bridge.multicast_snooping = Some(*v),
+            _ => {}
+        }
+    }
+    Some(bridge)
+}
+
+/// Returns the index of the bridge a link is a port of, with the port details.
+fn port_from_link(link: &LinkMessage) -> Option<(u32, BridgePort)> {
+    let mut master = None;
+    let mut infos: &[InfoBridgePort] = &[];
+    for nla in &link.attributes {
+        match nla {
+            LinkAttribute::Controller(index) => master = Some(*index),
+            LinkAttribute::LinkInfo(link_infos) => {
+                for info in link_infos {
+                    if let LinkInfo::PortData(InfoPortData::BridgePort(port)) = info {
+                        infos = port;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    let master = master?;
+    if infos.is_empty() {
+        return None;
+    }
+
+    let mut port = BridgePort {
+        index: link.header.index,
+        name: netlink::name_from_link(link).unwrap_or_default(),
+        state: PortState::Disabled,
+        priority: None,
+        cost: None,
+        port_id: None,
+        learning: None,
+        unicast_flood: None,
+        hairpin_mode: None,
+        bpdu_guard: None,
+        isolated: None,
+    };
+    for info in infos {
+        match info {
+            InfoBridgePort::State(state) => {
+                port.state = match state {
+                    link::BridgePortState::Disabled => PortState::Disabled,
+                    link::BridgePortState::Listening => PortState::Listening,
+                    link::BridgePortState::Learning => PortState::Learning,
+                    link::BridgePortState::Forwarding => PortState::Forwarding,
+                    link::BridgePortState::Blocking => PortState::Blocking,
+                    other => PortState::Other((*other).into()),
+                }
+            }
+            InfoBridgePort::Priority(v) => port.priority = Some(*v),
+            InfoBridgePort::Cost(v) => port.cost = Some(*v),
+            InfoBridgePort::PortId(v) => port.port_id = Some(*v),
+            InfoBridgePort::Learning(v) => port.learning = Some(*v),
+            InfoBridgePort::UnicastFlood(v) => port.unicast_flood = Some(*v),
+            InfoBridgePort::HairpinMode(v) => port.hairpin_mode = Some(*v),
+            InfoBridgePort::Guard(v) => port.bpdu_guard = Some(*v),
+            InfoBridgePort::Isolated(v) => port.isolated = Some(*v),
+            _ => {}
+        }
+    }
+    Some((master, port))
+}
+
+fn fdb_entry(msg: &NeighbourMessage, names: &HashMap<u32, String>) -> Option<FdbEntry> {
+    if msg.header.family != AddressFamily::Bridge {
+        return None;
+    }
+    let flags = msg.header.flags;
+    let mut entry = FdbEntry {
+        mac_addr: MacAddr::zero(),
+        if_index: msg.header.ifindex,
+        if_name: names.get(&msg.header.ifindex).cloned(),
+        master_index: None,
+        vlan: None,
+        state: match msg.header.state {
+            NeighbourState::Permanent => FdbState::Permanent,
+            NeighbourState::Noarp => FdbState::Static,
+            _ => FdbState::Dynamic,
+        },
+        remote: None,
+        vni: None,
+        extern_learned: flags.contains(NeighbourFlags::ExtLearned),
+        offloaded: flags.contains(NeighbourFlags::Offloaded),
+        sticky: flags.contains(NeighbourFlags::Sticky),
+        updated: None,
+    };
+    let mut mac = None;
+    for nla in &msg.attributes {
+        match nla {
+            NeighbourAttribute::LinkLayerAddress(bytes) => {
+                mac = <[u8; 6]>::try_from(bytes.as_slice()).ok();
+            }
+            NeighbourAttribute::Controller(index) => entry.master_index = Some(*index),
+            NeighbourAttribute::Vlan(vlan) => entry.vlan = Some(*vlan),
+            NeighbourAttribute::Vni(vni) => entry.vni = Some(*vni),
+            NeighbourAttribute::Destination(NeighbourAddress::Other(bytes)) => {
+                entry.remote = ip_from_bytes(bytes);
+            }
+            NeighbourAttribute::CacheInfo(info) => {
+                entry.updated = Some(clock_ticks_to_duration(info.updated.into()));
+            }
+            _ => {}
+        }
+    }
+    entry.mac_addr = MacAddr::from_octets(mac?);
+    Some(entry)
+}
+
+fn ip_from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+    if let Ok(v4) = <[u8; 4]>::try_from(bytes) {
+        Some(Ipv4Addr::from(v4).into())
+    } else if let Ok(v6) = <[u8; 16]>::try_from(bytes) {
+        Some(Ipv6Addr::from(v6).into())
+    } else {
+        None
+    }
+}
+
+fn nlas(bytes: &[u8]) -> impl Iterator<Item = NlaBuffer<&[u8]>> {
+    NlasIterator::new(bytes).map_while(Result::ok)
+}
+
+/// Parses one `RTM_NEWMDB` message: a `struct br_port_msg` followed by `MDBA_*` attributes.
+fn parse_mdb(payload: &[u8]) -> Vec<MdbEntry> {
+    let Some(header) = payload.get(..BR_PORT_MSG_LEN) else {
+        return Vec::new();
+    };
+    let bridge_index = u32::from_ne_bytes([header[4], header[5], header[6], header[7]]);
+
+    let mut out = Vec::new();
+    for mdb in nlas(&payload[BR_PORT_MSG_LEN..]).filter(|nla| nla.kind() == MDBA_MDB) {
+        for entry in nlas(mdb.value()).filter(|nla| nla.kind() == MDBA_MDB_ENTRY) {
+            for info in nlas(entry.value()).filter(|nla| nla.kind() == MDBA_MDB_ENTRY_INFO) {
+                out.extend(mdb_entry(bridge_index, info.value()));
+            }
+        }
+    }
+    out
+}
+
+/// Parses a `struct br_mdb_entry` and the `MDBA_MDB_EATTR_*` attributes that follow it.
+fn mdb_entry(bridge_index: u32, value: &[u8]) -> Option<MdbEntry> {
+    let e = value.get(..BR_MDB_ENTRY_LEN)?;
+    let addr = &e[8..24];
+    let group = match u16::from_be_bytes([e[24], e[25]]) {
+        ETH_P_IP => MdbGroup::Ip(ip_from_bytes(&addr[..4])?),
+        ETH_P_IPV6 => MdbGroup::Ip(ip_from_bytes(addr)?),
+        0 => MdbGroup::Mac(MacAddr::from_octets(addr[..6].try_into().ok()?)),
+        _ => return None,
+    };
+    let permanent = e[4] == MDB_PERMANENT;
+    let timer = nlas(&value[BR_MDB_ENTRY_LEN..])
+        .find(|nla| nla.kind() == MDBA_MDB_EATTR_TIMER)
+        .and_then(|nla| <[u8; 4]>::try_from(nla.value()).ok())
+        .map(|v| clock_ticks_to_duration(u32::from_ne_bytes(v).into()));
+    let vid = u16::from_ne_bytes([e[6], e[7]]);
+
+    Some(MdbEntry {
+        bridge_index,
+        port_index: u32::from_ne_bytes([e[0], e[1], e[2], e[3]]),
+        group,
+        vlan: (vid != 0).then_some(vid),
+        permanent,
+        offloaded: e[5] & MDB_FLAGS_OFFLOAD != 0,
+        timer: timer.filter(|_| !permanent),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bridge_from_link, fdb_entry, parse_mdb, port_from_link};
+    use crate::net::bridge::{FdbState, MdbGroup, PortState, StpState};
+    use crate::net::mac::MacAddr;
+    use netlink_packet_route::AddressFamily;
+    use netlink_packet_route::link::{
+        self, InfoBridge, InfoBridgePort, InfoData, InfoKind, InfoPortData, InfoPortKind,
+        LinkAttribute, LinkInfo, LinkMessage,
+    };
+    use netlink_packet_route::neighbour::{
+        NeighbourAttribute, NeighbourFlags, NeighbourMessage, NeighbourState,
+    };
+    use std::collections::HashMap;
+    use std::net::Ipv4Addr;
+
+    fn link(index: u32, name: &str, attributes: Vec<LinkAttribute>) -> LinkMessage {
+        let mut msg = LinkMessage::default();
+        msg.header.index = index;
+        msg.attributes.push(LinkAttribute::IfName(name.into()));
+        msg.attributes.extend(attributes);
+        msg
+    }
+
+    #[test]
+    fn parses_bridge_and_port() {
+        let br = link(
+            3,
+            "br0",
+            vec![LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Bridge),
+                LinkInfo::Data(InfoData::Bridge(vec![
+                    InfoBridge::StpState(link::BridgeStpState::KernelStp),
+                    InfoBridge::Priority(0x8000),
+                    InfoBridge::VlanFiltering(true),
+                    InfoBridge::VlanDefaultPvid(1),
+                ])),
+            ])],
+        );
+        let bridge = bridge_from_link(&br).unwrap();
+        assert_eq!(bridge.name, "br0");
+        assert_eq!(bridge.stp_state, StpState::Kernel);
+        assert_eq!(bridge.priority, Some(0x8000));
+        assert!(bridge.vlan_filtering);
+        assert_eq!(bridge.default_pvid, Some(1));
+        assert!(bridge_from_link(&link(2, "eth0", Vec::new())).is_none());
+
+        let veth = link(
+            5,
+            "veth123",
+            vec![
+                LinkAttribute::Controller(3),
+                LinkAttribute::LinkInfo(vec![
+                    LinkInfo::Kind(InfoKind::Veth),
+                    LinkInfo::PortKind(InfoPortKind::Bridge),
+                    LinkInfo::PortData(InfoPortData::BridgePort(vec![
+                        InfoBridgePort::State(link::BridgePortState::Forwarding),
+                        InfoBridgePort::Cost(2),
+                        InfoBridgePort::Priority(32),
+                    ])),
+                ]),
+            ],
+        );
+        let (master, port) = port_from_link(&veth).unwrap();
+        assert_eq!(master, 3);
+        assert_eq!((port.index, port.name.as_str()), (5, "veth123"));
+        assert_eq!(port.state, PortState::Forwarding);
+        assert_eq!((port.cost, port.priority), (Some(2), Some(32)));
+    }
+
+    #[test]
+    fn parses_fdb_entry() {
+        let mut msg = NeighbourMessage::default();
+        msg.header.family = AddressFamily::Bridge;
+        msg.header.ifindex = 5;
+        msg.header.state = NeighbourState::Noarp;
+        msg.header.flags = NeighbourFlags::Offloaded;
+        msg.attributes = vec![
+            NeighbourAttribute::LinkLayerAddress(vec![0x02, 0, 0, 0, 0, 0x01]),
+            NeighbourAttribute::Controller(3),
+            NeighbourAttribute::Vlan(10),
+        ];
+        let names = HashMap::from([(5, "veth123".to_string())]);
+
+        let entry = fdb_entry(&msg, &names).unwrap();
+        assert_eq!(entry.mac_addr, MacAddr::new(0x02, 0, 0, 0, 0, 0x01));
+        assert_eq!(entry.if_name.as_deref(), Some("veth123"));
+        assert_eq!((entry.master_index, entry.vlan), (Some(3), Some(10)));
+        assert_eq!(entry.state, FdbState::Static);
+        assert!(entry.offloaded && !entry.sticky);
+
+        msg.header.family = AddressFamily::Inet;
+        assert!(fdb_entry(&msg, &names).is_none());
+    }
+
+    #[test]
+    fn parses_mdb_message() {
+        // struct br_mdb_entry: port 5, temporary, vid 10, 239.1.1.1
+        let mut entry = Vec::new();
+        entry.extend(5u32.to_ne_bytes());
+        entry.extend([0, 0]);
+        entry.extend(10u16.to_ne_bytes());
+        let mut addr = [0u8; 16];
+        addr[..4].copy_from_slice(&[239, 1, 1, 1]);
+        entry.extend(addr);
+        entry.extend(0x0800u16.to_be_bytes());
+        entry.extend([0, 0]);
+        // MDBA_MDB_EATTR_TIMER
+        entry.extend(8u16.to_ne_bytes());
+        entry.extend(1u16.to_ne_bytes());
+        entry.extend(26000u32.to_ne_bytes());
+
+        let nest = |kind: u16, value: Vec<u8>| {
+            let mut out = Vec::new();
+            out.extend(((value.len() + 4) as u16).to_ne_bytes());
+            out.extend(kind.to_ne_bytes());
+            out.extend(value);
+            out
+        };
+        let mut payload = vec![7, 0, 0, 0];
+        payload.extend(3u32.to_ne_bytes());
+        payload.extend(nest(1, nest(1, nest(1, entry))));
+
+        let entries = parse_mdb(&payload);
+        assert_eq!(entries.len(), 1);
+        let e = &entries[0];
+        assert_eq!((e.bridge_index, e.port_index), (3, 5));
+        assert_eq!(e.group, MdbGroup::Ip(Ipv4Addr::new(239, 1, 1, 1).into()));
+        assert_eq!(e.vlan, Some(10));
+        assert!(!e.permanent);
+        assert!(e.timer.is_some());
+
+        assert!(parse_mdb(&[7, 0]).is_empty());
+    }
+}
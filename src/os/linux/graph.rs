@@ -0,0 +1,133 @@
+use super::{linkinfo, netlink, sysfs};
+use crate::interface::graph::{GraphEdge, GraphNode, InterfaceGraph, Relation};
+use crate::interface::kind::LinkKind;
+use netlink_packet_route::link::{LinkAttribute, LinkMessage};
+use std::collections::HashMap;
+use std::io;
+
+/// Builds the dependency graph of every link in the current network namespace.
+pub fn interface_graph() -> io::Result<InterfaceGraph> {
+    let links = netlink::dump_links()?;
+
+    let mut nodes = Vec::with_capacity(links.len());
+    let mut edges = Vec::new();
+    for link in &links {
+        let Some(name) = netlink::name_from_link(link) else {
+            continue;
+        };
+        let kind = linkinfo::link_kind(link);
+        edges.extend(link_edges(link, kind.as_ref()));
+        nodes.push(GraphNode {
+            index: link.header.index,
+            is_physical: !sysfs::is_virtual_interface(&name),
+            name,
+            kind,
+        });
+    }
+
+    // The adjacency symlinks also cover dependencies that netlink does not report, such as
+    // the lower devices of a macsec or team interface.
+    let by_name: HashMap<&str, u32> = nodes
+        .iter()
+        .map(|node| (node.name.as_str(), node.index))
+        .collect();
+    for node in &nodes {
+        for lower in sysfs::lower_interfaces(&node.name) {
+            if let Some(&lower) = by_name.get(lower.as_str()) {
+                edges.push(stacked(node.index, lower));
+            }
+        }
+        for upper in sysfs::upper_interfaces(&node.name) {
+            if let Some(&upper) = by_name.get(upper.as_str()) {
+                edges.push(stacked(upper, node.index));
+            }
+        }
+    }
+
+    Ok(InterfaceGraph::new(nodes, edges))
+}
+
+fn stacked(upper: u32, lower: u32) -> GraphEdge {
+    GraphEdge {
+        upper,
+        lower,
+        relation: Relation::Stacked,
+    }
+}
+
+/// Returns the edges carried by `IFLA_MASTER` and `IFLA_LINK`.
+///
+/// `IFLA_LINK` is ignored when it refers to another network namespace, and for veth devices,
+/// where it names the peer rather than a lower device.
+fn link_edges(link: &LinkMessage, kind: Option<&LinkKind>) -> Vec<GraphEdge> {
+    let index = link.header.index;
+    let other_netns = link
+        .attributes
+        .iter()
+        .any(|nla| matches!(nla, LinkAttribute::LinkNetNsId(_)));
+
+    let mut edges = Vec::new();
+    for nla in &link.attributes {
+        match nla {
+            LinkAttribute::Controller(master) => edges.push(GraphEdge {
+                upper: *master,
+                lower: index,
+                relation: Relation::Master,
+            }),
+            LinkAttribute::Link(lower)
+                if *lower != 0 && !other_netns && kind != Some(&LinkKind::Veth) =>
+            {
+                edges.push(stacked(index, *lower))
+            }
+            _ => {}
+        }
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::link_edges;
+    use crate::interface::graph::{GraphEdge, Relation};
+    use crate::interface::kind::LinkKind;
+    use netlink_packet_route::link::{LinkAttribute, LinkMessage};
+
+    fn link(index: u32, attributes: Vec<LinkAttribute>) -> LinkMessage {
+        let mut msg = LinkMessage::default();
+        msg.header.index = index;
+        msg.attributes = attributes;
+        msg
+    }
+
+    #[test]
+    fn extracts_master_and_parent() {
+        let vlan = link(
+            5,
+            vec![LinkAttribute::Link(4), LinkAttribute::Controller(6)],
+        );
+        assert_eq!(
+            link_edges(&vlan, None),
+            vec![
+                GraphEdge {
+                    upper: 5,
+                    lower: 4,
+                    relation: Relation::Stacked,
+                },
+                GraphEdge {
+                    upper: 6,
+                    lower: 5,
+                    relation: Relation::Master,
+                },
+            ]
+        );
+
+        let veth = link(7, vec![LinkAttribute::Link(8)]);
+        assert!(link_edges(&veth, Some(&LinkKind::Veth)).is_empty());
+
+        let remote = link(
+            9,
+            vec![LinkAttribute::Link(2), LinkAttribute::LinkNetNsId(0)],
+        );
+        assert!(link_edges(&remote, None).is_empty());
+    }
+}
@@ -1,16 +1,23 @@
-use super::netlink;
+use super::netlink::{self, LinkSelector};
 use crate::interface::interface::Interface;
+use crate::interface::options::CollectOptions;
 use crate::interface::state::OperState;
 use crate::ipnet::{Ipv4Net, Ipv6Net};
 use crate::net::mac::MacAddr;
 use crate::os::unix::interface::unix_interfaces;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use netlink_packet_route::link::LinkMessage;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 #[cfg(feature = "gateway")]
-use crate::net::device::NetworkDevice;
+use super::route::DefaultRoute;
+#[cfg(feature = "gateway")]
+use crate::net::device::{Gateway, NetworkDevice};
 #[cfg(feature = "gateway")]
 use crate::os::unix::dns::get_system_dns_conf;
 #[cfg(feature = "gateway")]
+use crate::route::types::IpFamily;
+#[cfg(feature = "gateway")]
 use std::collections::HashMap;
 
 fn push_ipv4(v: &mut Vec<Ipv4Net>, add: (Ipv4Addr, u8)) {
@@ -48,103 +55,179 @@ fn calc_v6_scope_id(addr: &Ipv6Addr, ifindex: u32) -> u32 {
 }
 
 pub fn interfaces() -> Vec<Interface> {
-    let mut ifaces = Vec::new();
-    // Fill ifaces via netlink first
-    // If netlink fails, fallback to unix_interfaces
-    match netlink::collect_interfaces() {
-        Ok(rows) => {
-            for r in rows {
-                let name = r.name.clone();
-                let mut iface = Interface {
-                    index: r.index,
-                    name: name.clone(),
-                    friendly_name: None,
-                    description: None,
-                    if_type: super::sysfs::get_interface_type(&name),
-                    mac_addr: r.mac.map(MacAddr::from_octets),
-                    ipv4: Vec::new(),
-                    ipv6: Vec::new(),
-                    ipv6_scope_ids: Vec::new(),
-                    ipv6_addr_flags: Vec::new(),
-                    flags: r.flags,
-                    oper_state: OperState::from_if_flags(r.flags),
-                    transmit_speed: None,
-                    receive_speed: None,
-                    auto_negotiate: None,
-                    dhcp_v4_enabled: None,
-                    dhcp_v6_enabled: None,
-                    stats: None,
-                    #[cfg(feature = "gateway")]
-                    gateway: None,
-                    #[cfg(feature = "gateway")]
-                    dns_servers: Vec::new(),
-                    mtu: r.mtu,
-                    #[cfg(feature = "gateway")]
-                    default: false,
-                };
-
-                for (a, p) in r.ipv4 {
-                    push_ipv4(&mut iface.ipv4, (a, p));
-                }
-                for (i, (a, p)) in r.ipv6.into_iter().enumerate() {
-                    if push_ipv6(&mut iface.ipv6, (a, p)) {
-                        iface.ipv6_scope_ids.push(calc_v6_scope_id(&a, iface.index));
-                        let raw = r.ipv6_addr_flags.get(i).copied().unwrap_or(0);
-                        iface
-                            .ipv6_addr_flags
-                            .push(super::ipv6_addr_flags::from_netlink_flags(raw));
-                    }
-                }
+    interfaces_with(&CollectOptions::all())
+}
 
-                ifaces.push(iface);
-            }
-        }
-        Err(_) => {
-            // Fallback: unix ifaddrs
-            ifaces = unix_interfaces();
+pub fn interfaces_with(options: &CollectOptions) -> Vec<Interface> {
+    collect(false, options).unwrap_or_default()
+}
+
+/// Like `interfaces`, but fails instead of falling back when netlink is unavailable.
+pub fn try_interfaces() -> io::Result<Vec<Interface>> {
+    collect(true, &CollectOptions::all())
+}
+
+/// Looks up one interface without enumerating the others.
+///
+/// Only the matched interface is enriched, and its route and neighbour data are requested
+/// filtered to its index. Falls back to a full enumeration when netlink is unavailable.
+fn single(
+    lookup: impl FnOnce() -> io::Result<Option<LinkMessage>>,
+    fallback: impl Fn(&Interface) -> bool,
+) -> Option<Interface> {
+    match lookup().and_then(|link| link.map(|l| netlink::collect_interface(&l)).transpose()) {
+        Ok(row) => {
+            let mut ifaces = vec![from_row(row?)];
+            let index = ifaces[0].index;
+            enrich(&mut ifaces, &CollectOptions::all(), Some(index));
+            ifaces.pop()
         }
+        Err(_) => interfaces().into_iter().find(fallback),
     }
+}
 
-    // Fill gateway info if feature enabled
-    #[cfg(feature = "gateway")]
-    match netlink::collect_routes() {
-        Ok(mut gmap) => {
-            for iface in &mut ifaces {
-                if iface.index == 0 {
-                    continue;
-                }
-                if let Some(row) = gmap.remove(&iface.index) {
-                    iface.gateway = Some(NetworkDevice {
-                        mac_addr: row.mac.map(MacAddr::from_octets).unwrap_or(MacAddr::zero()),
-                        ipv4: row.gw_v4,
-                        ipv6: row.gw_v6,
-                    });
-                }
+pub fn interface_by_index(index: u32) -> Option<Interface> {
+    single(
+        || netlink::get_link(LinkSelector::Index(index)),
+        |iface| iface.index == index,
+    )
+}
+
+pub fn interface_by_name(name: &str) -> Option<Interface> {
+    single(
+        || netlink::get_link(LinkSelector::Name(name)),
+        |iface| iface.name == name,
+    )
+}
+
+pub fn interface_by_mac(mac: MacAddr) -> Option<Interface> {
+    // The kernel cannot filter links by address, but a link-only dump is still far cheaper
+    // than a full enumeration.
+    let octets = mac.octets();
+    single(
+        || {
+            Ok(netlink::dump_links()?
+                .into_iter()
+                .find(|l| netlink::mac_from_link(l) == Some(octets)))
+        },
+        |iface| iface.mac_addr == Some(mac),
+    )
+}
+
+pub fn interface_by_ip(ip: IpAddr) -> Option<Interface> {
+    single(
+        || {
+            let index = netlink::dump_addrs()?
+                .iter()
+                .find(|a| netlink::ip_from_addr(a).is_some_and(|(addr, _, _)| addr == ip))
+                .map(|a| a.header.index);
+            match index {
+                Some(index) => netlink::get_link(LinkSelector::Index(index)),
+                None => Ok(None),
             }
-        }
+        },
+        |iface| match ip {
+            IpAddr::V4(v4) => iface.ipv4.iter().any(|n| n.addr() == v4),
+            IpAddr::V6(v6) => iface.ipv6.iter().any(|n| n.addr() == v6),
+        },
+    )
+}
+
+fn collect(strict: bool, options: &CollectOptions) -> io::Result<Vec<Interface>> {
+    // Fill ifaces via netlink first
+    // If netlink fails, fallback to unix_interfaces
+    let mut ifaces = match netlink::collect_interfaces() {
+        Ok(rows) => rows.into_iter().map(from_row).collect(),
+        Err(e) if strict => return Err(e),
         Err(_) => {
-            // Fallback: procfs
-            let mut gateway_map: HashMap<String, NetworkDevice> = super::procfs::get_gateway_map();
+            let mut ifaces = unix_interfaces();
             for iface in &mut ifaces {
-                if let Some(gateway) = gateway_map.remove(&iface.name) {
-                    iface.gateway = Some(gateway);
-                }
+                iface.oper_state = super::sysfs::operstate(&iface.name);
             }
+            ifaces
+        }
+    };
+    enrich(&mut ifaces, options, None);
+    Ok(ifaces)
+}
+
+fn from_row(r: netlink::IfRow) -> Interface {
+    let name = r.name.clone();
+    let mut iface = Interface {
+        index: r.index,
+        name: name.clone(),
+        friendly_name: None,
+        description: None,
+        if_type: super::sysfs::interface_type_from_arphrd(r.link_type as u32),
+        link_kind: r.kind,
+        tunnel: r.tunnel,
+        mac_addr: r.mac.map(MacAddr::from_octets),
+        ipv4: Vec::new(),
+        ipv6: Vec::new(),
+        ipv6_scope_ids: Vec::new(),
+        ipv6_addr_flags: Vec::new(),
+        flags: r.flags,
+        oper_state: r
+            .oper_state
+            .unwrap_or_else(|| OperState::from_if_flags(r.flags)),
+        transmit_speed: None,
+        receive_speed: None,
+        auto_negotiate: None,
+        dhcp_v4_enabled: None,
+        dhcp_v6_enabled: None,
+        stats: r.stats,
+        #[cfg(feature = "gateway")]
+        gateway: None,
+        #[cfg(feature = "gateway")]
+        gateways: Vec::new(),
+        #[cfg(feature = "gateway")]
+        dns_servers: Vec::new(),
+        mtu: r.mtu,
+        #[cfg(feature = "gateway")]
+        default: false,
+        #[cfg(feature = "gateway")]
+        default_v4: false,
+        #[cfg(feature = "gateway")]
+        default_v6: false,
+    };
+
+    for (a, p) in r.ipv4 {
+        push_ipv4(&mut iface.ipv4, (a, p));
+    }
+    for (i, (a, p)) in r.ipv6.into_iter().enumerate() {
+        if push_ipv6(&mut iface.ipv6, (a, p)) {
+            iface.ipv6_scope_ids.push(calc_v6_scope_id(&a, iface.index));
+            let raw = r.ipv6_addr_flags.get(i).copied().unwrap_or(0);
+            iface
+                .ipv6_addr_flags
+                .push(super::ipv6_addr_flags::from_netlink_flags(raw));
         }
     }
 
-    // Fill other info
-    for iface in &mut ifaces {
-        iface.if_type = super::sysfs::get_interface_type(&iface.name);
-        let if_speed = super::sysfs::get_interface_speed(&iface.name);
-        iface.transmit_speed = if_speed;
-        iface.receive_speed = if_speed;
-        let dhcp = super::dhcp::dhcp_state(&iface.name, iface.index);
-        iface.dhcp_v4_enabled = dhcp.v4;
-        iface.dhcp_v6_enabled = dhcp.v6;
-        iface.oper_state = super::sysfs::operstate(&iface.name);
+    iface
+}
+
+/// Fills the details that need per-interface probes or route data.
+///
+/// `scope` restricts the route and neighbour requests to one interface index, for lookups
+/// that enrich only that interface.
+fn enrich(ifaces: &mut [Interface], options: &CollectOptions, scope: Option<u32>) {
+    for iface in ifaces.iter_mut() {
+        if options.type_refinement {
+            iface.if_type = super::sysfs::get_interface_type(&iface.name);
+        }
+        if options.link_speed {
+            let if_speed = super::sysfs::get_interface_speed(&iface.name);
+            iface.transmit_speed = if_speed;
+            iface.receive_speed = if_speed;
+        }
+        if options.dhcp {
+            let dhcp = super::dhcp::dhcp_state(&iface.name, iface.index);
+            iface.dhcp_v4_enabled = dhcp.v4;
+            iface.dhcp_v6_enabled = dhcp.v6;
+        }
 
-        if iface.stats.is_none() {
+        if options.stats && iface.stats.is_none() {
             iface.stats = crate::stats::counters::get_stats_from_name(&iface.name);
         }
 
@@ -153,15 +236,192 @@ pub fn interfaces() -> Vec<Interface> {
         }
     }
     #[cfg(feature = "gateway")]
-    {
-        if let Some(local_ip) = crate::net::ip::get_local_ipaddr() {
-            if let Some(idx) = crate::interface::pick_default_iface_index(&ifaces, local_ip) {
-                if let Some(iface) = ifaces.iter_mut().find(|it| it.index == idx) {
-                    iface.default = true;
-                    iface.dns_servers = get_system_dns_conf();
+    enrich_routes(ifaces, options, scope, &KernelRoutes);
+    #[cfg(not(feature = "gateway"))]
+    let _ = scope;
+}
+
+/// Route data used by `enrich_routes`. Tests substitute it to observe which lookups run.
+#[cfg(feature = "gateway")]
+trait RouteSource {
+    /// Returns the default route gateways keyed by interface index, only those of `scope`
+    /// when it is set.
+    fn gateways(&self, scope: Option<u32>) -> io::Result<HashMap<u32, netlink::GwRow>>;
+    /// Resolves the route of Internet-bound traffic of `family`.
+    fn default_route(&self, family: IpFamily) -> io::Result<Option<DefaultRoute>>;
+}
+
+#[cfg(feature = "gateway")]
+struct KernelRoutes;
+
+#[cfg(feature = "gateway")]
+impl RouteSource for KernelRoutes {
+    fn gateways(&self, scope: Option<u32>) -> io::Result<HashMap<u32, netlink::GwRow>> {
+        netlink::collect_routes(scope)
+    }
+
+    fn default_route(&self, family: IpFamily) -> io::Result<Option<DefaultRoute>> {
+        super::route::default_route_for(family)
+    }
+}
+
+/// Fills the gateways, the default interface flags, and the DNS servers.
+///
+/// Route dumps run only when `options.gateway` is set. The default route lookups run when
+/// gateways or DNS servers are requested, since DNS servers are attached to the default
+/// interface.
+#[cfg(feature = "gateway")]
+fn enrich_routes(
+    ifaces: &mut [Interface],
+    options: &CollectOptions,
+    scope: Option<u32>,
+    source: &impl RouteSource,
+) {
+    if options.gateway {
+        match source.gateways(scope) {
+            Ok(mut gmap) => {
+                for iface in ifaces.iter_mut() {
+                    if iface.index == 0 {
+                        continue;
+                    }
+                    if let Some(row) = gmap.remove(&iface.index) {
+                        iface.gateway = Some(NetworkDevice {
+                            mac_addr: row.mac.map(MacAddr::from_octets).unwrap_or(MacAddr::zero()),
+                            ipv4: row.gw_v4,
+                            ipv6: row.gw_v6,
+                        });
+                        iface.gateways = row
+                            .entries
+                            .into_iter()
+                            .map(|e| Gateway {
+                                mac_addr: e.mac.map(MacAddr::from_octets),
+                                metric: Some(e.metric),
+                                table: Some(e.table),
+                                ..Gateway::new(e.ip)
+                            })
+                            .collect();
+                    }
+                }
+            }
+            Err(_) => {
+                // Fallback: procfs
+                let mut gateway_map: HashMap<String, NetworkDevice> =
+                    super::procfs::get_gateway_map();
+                for iface in ifaces.iter_mut() {
+                    if let Some(gateway) = gateway_map.remove(&iface.name) {
+                        iface.gateway = Some(gateway);
+                    }
                 }
             }
         }
     }
-    ifaces
+
+    if !options.gateway && !options.dns {
+        return;
+    }
+    let default_v4 = default_index(ifaces, source, IpFamily::V4);
+    let default_v6 = default_index(ifaces, source, IpFamily::V6);
+    for iface in ifaces.iter_mut() {
+        iface.default_v4 = default_v4 == Some(iface.index);
+        iface.default_v6 = default_v6 == Some(iface.index);
+    }
+    if let Some(idx) = default_v4.or(default_v6)
+        && let Some(iface) = ifaces.iter_mut().find(|it| it.index == idx)
+    {
+        iface.default = true;
+        if options.dns {
+            iface.dns_servers = get_system_dns_conf();
+        }
+    }
+}
+
+/// Returns the index of the interface that Internet-bound traffic of `family` leaves through.
+///
+/// The kernel is asked for the route it would use, so policy routing is honored. The local
+/// address heuristic is used only when the lookup itself fails, never when the kernel answered
+/// that the family has no default route.
+#[cfg(feature = "gateway")]
+fn default_index(ifaces: &[Interface], source: &impl RouteSource, family: IpFamily) -> Option<u32> {
+    use crate::net::ip::{try_ipv4, try_ipv6};
+
+    match source.default_route(family) {
+        Ok(route) => route.map(|rt| rt.if_index),
+        Err(_) => {
+            let local = match family {
+                IpFamily::V4 => try_ipv4(),
+                IpFamily::V6 => try_ipv6(),
+            };
+            local.and_then(|ip| crate::interface::pick_default_iface_index(ifaces, ip))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg(feature = "gateway")]
+    use super::{RouteSource, enrich_routes};
+    use crate::interface::interface::Interface;
+    use crate::interface::options::CollectOptions;
+    use crate::os::linux::netlink::GwRow;
+    use crate::os::linux::route::DefaultRoute;
+    use crate::route::types::IpFamily;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::io;
+
+    /// Records the lookups instead of asking the kernel.
+    #[derive(Default)]
+    struct Recorder {
+        gateways: RefCell<Vec<Option<u32>>>,
+        defaults: RefCell<Vec<IpFamily>>,
+    }
+
+    impl RouteSource for Recorder {
+        fn gateways(&self, scope: Option<u32>) -> io::Result<HashMap<u32, GwRow>> {
+            self.gateways.borrow_mut().push(scope);
+            Ok(HashMap::new())
+        }
+
+        fn default_route(&self, family: IpFamily) -> io::Result<Option<DefaultRoute>> {
+            self.defaults.borrow_mut().push(family);
+            Ok(Some(DefaultRoute {
+                if_index: 2,
+                gateway: None,
+            }))
+        }
+    }
+
+    fn ifaces() -> Vec<Interface> {
+        let mut iface = Interface::dummy();
+        iface.index = 2;
+        vec![iface]
+    }
+
+    #[test]
+    fn minimal_options_skip_route_lookups() {
+        let source = Recorder::default();
+        let mut ifaces = ifaces();
+
+        enrich_routes(&mut ifaces, &CollectOptions::minimal(), None, &source);
+
+        assert!(source.gateways.borrow().is_empty());
+        assert!(source.defaults.borrow().is_empty());
+        assert!(!ifaces[0].default);
+    }
+
+    #[test]
+    fn scoped_enrichment_filters_route_requests() {
+        let source = Recorder::default();
+        let mut ifaces = ifaces();
+        let options = CollectOptions {
+            dns: false,
+            ..CollectOptions::all()
+        };
+
+        enrich_routes(&mut ifaces, &options, Some(2), &source);
+
+        assert_eq!(*source.gateways.borrow(), vec![Some(2)]);
+        assert_eq!(*source.defaults.borrow(), vec![IpFamily::V4, IpFamily::V6]);
+        assert!(ifaces[0].default && ifaces[0].default_v4 && ifaces[0].default_v6);
+    }
 }
@@ -0,0 +1,506 @@
+use crate::interface::kind::{IpVlanMode, LinkKind, MacVlanMode, VlanInfo};
+use crate::interface::tunnel::{TunnelEncap, TunnelInfo};
+use netlink_packet_core::Nla;
+use netlink_packet_route::link::{
+    self, GreEncapFlags, GreEncapType, GreIOFlags, InfoData, InfoGeneve, InfoGre, InfoGre6,
+    InfoIpTunnel, InfoIpVlan, InfoKind, InfoMacVlan, InfoMacVtap, InfoTun, InfoVlan, InfoVrf,
+    InfoVxlan, LinkAttribute, LinkInfo, LinkMessage, TunnelEncapFlags, TunnelEncapType,
+};
+use std::net::IpAddr;
+
+const IFLA_TUN_TYPE: u16 = 1;
+const IFF_TAP: u8 = 0x02;
+const IFLA_GRE_LINK: u16 = 1;
+const IFLA_GRE_FLAGS: u16 = 18;
+const IP6_TNL_F_USE_ORIG_TCLASS: u32 = 0x2;
+const IP6_TNL_F_USE_ORIG_FLOWLABEL: u32 = 0x4;
+const IPV6_TCLASS_SHIFT: u32 = 20;
+const IPV6_FLOWLABEL_MASK: u32 = 0x000f_ffff;
+
+/// Returns the `IFLA_INFO_KIND` and `IFLA_INFO_DATA` of a link.
+pub fn link_info(link: &LinkMessage) -> Option<(&InfoKind, Option<&InfoData>)> {
+    let infos = link.attributes.iter().find_map(|nla| match nla {
+        LinkAttribute::LinkInfo(infos) => Some(infos),
+        _ => None,
+    })?;
+    let kind = infos.iter().find_map(|info| match info {
+        LinkInfo::Kind(kind) => Some(kind),
+        _ => None,
+    })?;
+    let data = infos.iter().find_map(|info| match info {
+        LinkInfo::Data(data) => Some(data),
+        _ => None,
+    });
+    Some((kind, data))
+}
+
+/// Maps the `IFLA_LINKINFO` attribute of a link to a `LinkKind`.
+///
+/// Returns `None` for links without a driver kind, such as physical NICs and loopback.
+pub fn link_kind(link: &LinkMessage) -> Option<LinkKind> {
+    let (kind, data) = link_info(link)?;
+    let kind = match kind {
+        InfoKind::Bridge => LinkKind::Bridge,
+        InfoKind::Bond => LinkKind::Bond,
+        InfoKind::Vlan => match data {
+            Some(InfoData::Vlan(infos)) => vlan_info(infos)
+                .map(LinkKind::Vlan)
+                .unwrap_or_else(|| LinkKind::Other(kind.to_string())),
+            _ => LinkKind::Other(kind.to_string()),
+        },
+        InfoKind::Veth => LinkKind::Veth,
+        InfoKind::MacVlan => LinkKind::MacVlan(match data {
+            Some(InfoData::MacVlan(infos)) => infos
+                .iter()
+                .find_map(|info| match info {
+                    InfoMacVlan::Mode(mode) => Some(macvlan_mode(*mode)),
+                    _ => None,
+                })
+                .unwrap_or(MacVlanMode::Vepa),
+            _ => MacVlanMode::Vepa,
+        }),
+        InfoKind::MacVtap => LinkKind::MacVtap(match data {
+            Some(InfoData::MacVtap(infos)) => infos
+                .iter()
+                .find_map(|info| match info {
+                    InfoMacVtap::Mode(mode) => Some(macvlan_mode(*mode)),
+                    _ => None,
+                })
+                .unwrap_or(MacVlanMode::Vepa),
+            _ => MacVlanMode::Vepa,
+        }),
+        InfoKind::IpVlan => LinkKind::IpVlan(match data {
+            Some(InfoData::IpVlan(infos)) => infos
+                .iter()
+                .find_map(|info| match info {
+                    InfoIpVlan::Mode(mode) => Some(ipvlan_mode(*mode)),
+                    _ => None,
+                })
+                .unwrap_or(IpVlanMode::L3),
+            _ => IpVlanMode::L3,
+        }),
+        InfoKind::Vxlan => LinkKind::Vxlan,
+        InfoKind::Geneve => LinkKind::Geneve,
+        InfoKind::Wireguard => LinkKind::WireGuard,
+        InfoKind::Tun => match data {
+            Some(InfoData::Tun(infos)) if is_tap(infos) => LinkKind::Tap,
+            _ => LinkKind::Tun,
+        },
+        InfoKind::Dummy => LinkKind::Dummy,
+        InfoKind::Vrf => LinkKind::Vrf {
+            table: match data {
+                Some(InfoData::Vrf(infos)) => infos.iter().find_map(|info| match info {
+                    InfoVrf::TableId(table) => Some(*table),
+                    _ => None,
+                }),
+                _ => None,
+            },
+        },
+        InfoKind::GreTun | InfoKind::GreTun6 => LinkKind::Gre,
+        InfoKind::GreTap | InfoKind::GreTap6 => LinkKind::GreTap,
+        InfoKind::IpIp => LinkKind::IpIp,
+        InfoKind::SitTun => LinkKind::Sit,
+        InfoKind::Ip6Tnl => LinkKind::Ip6Tnl,
+        InfoKind::Vti => LinkKind::Vti,
+        InfoKind::Xfrm => LinkKind::Xfrm,
+        InfoKind::MacSec => LinkKind::MacSec,
+        InfoKind::Ifb => LinkKind::Ifb,
+        InfoKind::Other(name) if name == "vti6" => LinkKind::Vti,
+        other => LinkKind::Other(other.to_string()),
+    };
+    Some(kind)
+}
+
+/// Reads the endpoints and parameters of a VXLAN, Geneve, GRE, IPIP, SIT, or ip6tnl link
+/// from its `IFLA_INFO_DATA`.
+pub fn tunnel_info(link: &LinkMessage) -> Option<TunnelInfo> {
+    let (_, data) = link_info(link)?;
+    let mut info = TunnelInfo::default();
+    match data? {
+        InfoData::Vxlan(nlas) => vxlan_tunnel(nlas, &mut info),
+        InfoData::Geneve(nlas) => geneve_tunnel(nlas, &mut info),
+        InfoData::GreTun(nlas) | InfoData::GreTap(nlas) => gre_tunnel(nlas, &mut info),
+        InfoData::GreTun6(nlas) | InfoData::GreTap6(nlas) => gre6_tunnel(nlas, &mut info),
+        InfoData::IpTunnel(nlas) => ip_tunnel(nlas, &mut info),
+        _ => return None,
+    }
+    Some(info)
+}
+
+/// Outer TTL, where `0` means inherited from the inner packet.
+fn ttl(ttl: u8) -> Option<u8> {
+    (ttl != 0).then_some(ttl)
+}
+
+/// Outer TOS, where `1` means inherited from the inner packet.
+fn tos(tos: u8) -> Option<u8> {
+    (tos != 1).then_some(tos)
+}
+
+fn vxlan_tunnel(nlas: &[InfoVxlan], info: &mut TunnelInfo) {
+    for nla in nlas {
+        match nla {
+            InfoVxlan::Id(vni) => info.vni = Some(*vni),
+            InfoVxlan::Group(ip) => info.remote = Some(IpAddr::V4(*ip)),
+            InfoVxlan::Group6(ip) => info.remote = Some(IpAddr::V6(*ip)),
+            InfoVxlan::Local(ip) => info.local = Some(IpAddr::V4(*ip)),
+            InfoVxlan::Local6(ip) => info.local = Some(IpAddr::V6(*ip)),
+            InfoVxlan::Port(port) => info.port = Some(*port),
+            InfoVxlan::Link(index) => info.underlay_index = Some(*index),
+            InfoVxlan::Ttl(v) => info.ttl = ttl(*v),
+            InfoVxlan::Tos(v) => info.tos = tos(*v),
+            InfoVxlan::UDPCsum(v) => info.flags.checksum = *v,
+            InfoVxlan::RemCsumTX(v) => info.flags.remote_checksum = *v,
+            InfoVxlan::CollectMetadata(v) => info.flags.collect_metadata = *v,
+            InfoVxlan::TtlInherit(v) => info.flags.ttl_inherit = *v,
+            _ => {}
+        }
+    }
+}
+
+fn geneve_tunnel(nlas: &[InfoGeneve], info: &mut TunnelInfo) {
+    for nla in nlas {
+        match nla {
+            InfoGeneve::Id(vni) => info.vni = Some(*vni),
+            InfoGeneve::Remote(ip) => info.remote = Some(IpAddr::V4(*ip)),
+            InfoGeneve::Remote6(ip) => info.remote = Some(IpAddr::V6(*ip)),
+            InfoGeneve::Port(port) => info.port = Some(*port),
+            InfoGeneve::Ttl(v) => info.ttl = ttl(*v),
+            InfoGeneve::Tos(v) => info.tos = tos(*v),
+            InfoGeneve::UdpCsum(v) => info.flags.checksum = *v,
+            InfoGeneve::CollectMetadata => info.flags.collect_metadata = true,
+            InfoGeneve::TtlInherit(v) => info.flags.ttl_inherit = *v,
+            _ => {}
+        }
+    }
+}
+
+fn gre_encap(encap: &GreEncapType) -> Option<TunnelEncap> {
+    match encap {
+        GreEncapType::None => None,
+        GreEncapType::Fou => Some(TunnelEncap::Fou),
+        GreEncapType::Gue => Some(TunnelEncap::Gue),
+        other => Some(TunnelEncap::Other(other.into())),
+    }
+}
+
+fn gre_flags(flags: GreIOFlags, info: &mut TunnelInfo) {
+    info.flags.checksum |= flags.contains(GreIOFlags::Checksum);
+    info.flags.sequence |= flags.contains(GreIOFlags::Seq);
+}
+
+fn gre_tunnel(nlas: &[InfoGre], info: &mut TunnelInfo) {
+    for nla in nlas {
+        match nla {
+            InfoGre::Link(v) if *v != 0 => info.underlay_index = Some(*v),
+            InfoGre::Local(ip) if !ip.is_unspecified() => info.local = Some((*ip).into()),
+            InfoGre::Remote(ip) if !ip.is_unspecified() => info.remote = Some((*ip).into()),
+            InfoGre::IFlags(flags) if flags.contains(GreIOFlags::Key) => {
+                info.input_key = nlas.iter().find_map(|nla| match nla {
+                    InfoGre::IKey(key) => Some(*key),
+                    _ => None,
+                });
+            }
+            InfoGre::OFlags(flags) => {
+                gre_flags(*flags, info);
+                if flags.contains(GreIOFlags::Key) {
+                    info.output_key = nlas.iter().find_map(|nla| match nla {
+                        InfoGre::OKey(key) => Some(*key),
+                        _ => None,
+                    });
+                }
+            }
+            InfoGre::Ttl(v) => {
+                info.ttl = ttl(*v);
+                info.flags.ttl_inherit = *v == 0;
+            }
+            InfoGre::Tos(v) => info.tos = tos(*v),
+            InfoGre::PathMTUDiscovery(v) => info.flags.pmtu_discovery = *v,
+            InfoGre::EncapType(encap) => info.encap = gre_encap(encap),
+            InfoGre::EncapFlags(flags) => {
+                info.flags.remote_checksum = flags.contains(GreEncapFlags::RemoteChecksum)
+            }
+            InfoGre::DestinationPort(port) if *port != 0 => info.port = Some(*port),
+            InfoGre::CollectMetadata => info.flags.collect_metadata = true,
+            _ => {}
+        }
+    }
+}
+
+/// Reads a `u32` attribute that `InfoGre6` leaves undecoded.
+fn gre6_u32(nlas: &[InfoGre6], kind: u16) -> Option<u32> {
+    nlas.iter().find_map(|nla| match nla {
+        InfoGre6::Other(nla) if nla.kind() == kind && nla.value_len() == 4 => {
+            let mut value = [0u8; 4];
+            nla.emit_value(&mut value);
+            Some(u32::from_ne_bytes(value))
+        }
+        _ => None,
+    })
+}
+
+/// Splits the flow information of an IPv6 tunnel into its traffic class and flow label,
+/// skipping those that `flags` copies from the inner packet.
+fn ip6_flowinfo(flowinfo: u32, flags: u32, info: &mut TunnelInfo) {
+    if flags & IP6_TNL_F_USE_ORIG_TCLASS == 0 {
+        info.tos = Some((flowinfo >> IPV6_TCLASS_SHIFT) as u8);
+    }
+    if flags & IP6_TNL_F_USE_ORIG_FLOWLABEL == 0 {
+        info.flowlabel = Some(flowinfo & IPV6_FLOWLABEL_MASK);
+    }
+}
+
+fn gre6_tunnel(nlas: &[InfoGre6], info: &mut TunnelInfo) {
+    info.underlay_index = gre6_u32(nlas, IFLA_GRE_LINK).filter(|index| *index != 0);
+    let flags = gre6_u32(nlas, IFLA_GRE_FLAGS).unwrap_or(0);
+    for nla in nlas {
+        match nla {
+            InfoGre6::FlowLabel(flowinfo) => ip6_flowinfo(*flowinfo, flags, info),
+            InfoGre6::Local(ip) if !ip.is_unspecified() => info.local = Some((*ip).into()),
+            InfoGre6::Remote(ip) if !ip.is_unspecified() => info.remote = Some((*ip).into()),
+            InfoGre6::IFlags(flags) if flags.contains(GreIOFlags::Key) => {
+                info.input_key = nlas.iter().find_map(|nla| match nla {
+                    InfoGre6::IKey(key) => Some(*key),
+                    _ => None,
+                });
+            }
+            InfoGre6::OFlags(flags) => {
+                gre_flags(*flags, info);
+                if flags.contains(GreIOFlags::Key) {
+                    info.output_key = nlas.iter().find_map(|nla| match nla {
+                        InfoGre6::OKey(key) => Some(*key),
+                        _ => None,
+                    });
+                }
+            }
+            InfoGre6::Ttl(v) => {
+                info.ttl = ttl(*v);
+                info.flags.ttl_inherit = *v == 0;
+            }
+            InfoGre6::EncapType(encap) => info.encap = gre_encap(encap),
+            InfoGre6::EncapFlags(flags) => {
+                info.flags.remote_checksum = flags.contains(GreEncapFlags::RemoteChecksum)
+            }
+            InfoGre6::DestinationPort(port) if *port != 0 => info.port = Some(*port),
+            InfoGre6::CollectMetadata => info.flags.collect_metadata = true,
+            _ => {}
+        }
+    }
+}
+
+fn ip_tunnel(nlas: &[InfoIpTunnel], info: &mut TunnelInfo) {
+    let ip6_flags = nlas.iter().find_map(|nla| match nla {
+        InfoIpTunnel::Ipv6Flags(flags) => Some(flags.bits()),
+        _ => None,
+    });
+    for nla in nlas {
+        match nla {
+            // ip6tnl sends `IFLA_IPTUN_FLOWINFO` in network byte order, but it is decoded in
+            // host byte order.
+            InfoIpTunnel::FlowInfo(flowinfo) => {
+                ip6_flowinfo(u32::from_be(*flowinfo), ip6_flags.unwrap_or(0), info)
+            }
+            InfoIpTunnel::Link(v) if *v != 0 => info.underlay_index = Some(*v),
+            InfoIpTunnel::Local(ip) if !ip.is_unspecified() => info.local = Some(*ip),
+            InfoIpTunnel::Remote(ip) if !ip.is_unspecified() => info.remote = Some(*ip),
+            InfoIpTunnel::Ttl(v) => {
+                info.ttl = ttl(*v);
+                info.flags.ttl_inherit = *v == 0;
+            }
+            InfoIpTunnel::Tos(v) => info.tos = tos(*v),
+            InfoIpTunnel::PMtuDisc(v) => info.flags.pmtu_discovery = *v,
+            InfoIpTunnel::EncapType(encap) => {
+                info.encap = match encap {
+                    TunnelEncapType::None => None,
+                    TunnelEncapType::Fou => Some(TunnelEncap::Fou),
+                    TunnelEncapType::Gue => Some(TunnelEncap::Gue),
+                    TunnelEncapType::Mpls => Some(TunnelEncap::Mpls),
+                    other => Some(TunnelEncap::Other((*other).into())),
+                }
+            }
+            InfoIpTunnel::EncapFlags(flags) => {
+                info.flags.remote_checksum = flags.contains(TunnelEncapFlags::RemCSum)
+            }
+            InfoIpTunnel::EncapDPort(port) if *port != 0 => info.port = Some(*port),
+            InfoIpTunnel::CollectMetadata => info.flags.collect_metadata = true,
+            _ => {}
+        }
+    }
+}
+
+fn vlan_info(infos: &[InfoVlan]) -> Option<VlanInfo> {
+    let id = infos.iter().find_map(|info| match info {
+        InfoVlan::Id(id) => Some(*id),
+        _ => None,
+    })?;
+    let protocol = infos
+        .iter()
+        .find_map(|info| match info {
+            InfoVlan::Protocol(protocol) => Some(u16::from(*protocol).into()),
+            _ => None,
+        })
+        .unwrap_or(crate::interface::kind::VlanProtocol::Ieee8021Q);
+    Some(VlanInfo { id, protocol })
+}
+
+fn macvlan_mode(mode: link::MacVlanMode) -> MacVlanMode {
+    match mode {
+        link::MacVlanMode::Private => MacVlanMode::Private,
+        link::MacVlanMode::Vepa => MacVlanMode::Vepa,
+        link::MacVlanMode::Bridge => MacVlanMode::Bridge,
+        link::MacVlanMode::Passthrough => MacVlanMode::Passthrough,
+        link::MacVlanMode::Source => MacVlanMode::Source,
+        other => MacVlanMode::Unknown(other.into()),
+    }
+}
+
+fn ipvlan_mode(mode: link::IpVlanMode) -> IpVlanMode {
+    match mode {
+        link::IpVlanMode::L2 => IpVlanMode::L2,
+        link::IpVlanMode::L3 => IpVlanMode::L3,
+        link::IpVlanMode::L3S => IpVlanMode::L3s,
+        other => IpVlanMode::Unknown(other.into()),
+    }
+}
+
+/// Returns `true` when the `IFLA_TUN_TYPE` of a tun device selects TAP mode.
+fn is_tap(infos: &[InfoTun]) -> bool {
+    infos.iter().any(|info| match info {
+        InfoTun::Other(nla) if nla.kind() == IFLA_TUN_TYPE && nla.value_len() == 1 => {
+            let mut value = [0u8; 1];
+            nla.emit_value(&mut value);
+            value[0] == IFF_TAP
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{link_kind, tunnel_info};
+    use crate::interface::kind::{LinkKind, VlanInfo, VlanProtocol};
+    use crate::interface::tunnel::TunnelEncap;
+    use netlink_packet_core::DefaultNla;
+    use netlink_packet_route::link::{
+        self, GreEncapType, GreIOFlags, InfoData, InfoGre, InfoGre6, InfoIpTunnel, InfoKind,
+        InfoVlan, InfoVxlan, Ip6TunnelFlags, LinkAttribute, LinkInfo, LinkMessage,
+    };
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn link(infos: Vec<LinkInfo>) -> LinkMessage {
+        let mut msg = LinkMessage::default();
+        msg.attributes.push(LinkAttribute::LinkInfo(infos));
+        msg
+    }
+
+    #[test]
+    fn maps_kinds_and_details() {
+        assert_eq!(link_kind(&LinkMessage::default()), None);
+        assert_eq!(
+            link_kind(&link(vec![LinkInfo::Kind(InfoKind::Bridge)])),
+            Some(LinkKind::Bridge)
+        );
+
+        let vlan = link(vec![
+            LinkInfo::Kind(InfoKind::Vlan),
+            LinkInfo::Data(InfoData::Vlan(vec![
+                InfoVlan::Id(100),
+                InfoVlan::Protocol(link::VlanProtocol::Ieee8021Ad),
+            ])),
+        ]);
+        assert_eq!(
+            link_kind(&vlan),
+            Some(LinkKind::Vlan(VlanInfo {
+                id: 100,
+                protocol: VlanProtocol::Ieee8021Ad,
+            }))
+        );
+
+        let vxlan = link(vec![
+            LinkInfo::Kind(InfoKind::Vxlan),
+            LinkInfo::Data(InfoData::Vxlan(vec![
+                InfoVxlan::Id(42),
+                InfoVxlan::Group(Ipv4Addr::new(239, 1, 1, 1)),
+                InfoVxlan::Port(4789),
+            ])),
+        ]);
+        assert_eq!(link_kind(&vxlan), Some(LinkKind::Vxlan));
+        let info = tunnel_info(&vxlan).expect("vxlan tunnel");
+        assert_eq!((info.vni, info.port), (Some(42), Some(4789)));
+        assert_eq!(info.remote, Some(Ipv4Addr::new(239, 1, 1, 1).into()));
+
+        let other = link(vec![LinkInfo::Kind(InfoKind::Other("batadv".into()))]);
+        assert_eq!(link_kind(&other), Some(LinkKind::Other("batadv".into())));
+    }
+
+    #[test]
+    fn reads_gre_tunnel() {
+        let gre = link(vec![
+            LinkInfo::Kind(InfoKind::GreTun),
+            LinkInfo::Data(InfoData::GreTun(vec![
+                InfoGre::Link(4),
+                InfoGre::IFlags(GreIOFlags::Key),
+                InfoGre::OFlags(GreIOFlags::Key | GreIOFlags::Checksum),
+                InfoGre::IKey(7),
+                InfoGre::OKey(8),
+                InfoGre::Local(Ipv4Addr::new(192, 0, 2, 1)),
+                InfoGre::Remote(Ipv4Addr::new(198, 51, 100, 1)),
+                InfoGre::Ttl(0),
+                InfoGre::Tos(1),
+                InfoGre::PathMTUDiscovery(true),
+                InfoGre::EncapType(GreEncapType::Fou),
+                InfoGre::DestinationPort(5555),
+            ])),
+        ]);
+        let info = tunnel_info(&gre).expect("gre tunnel");
+        assert_eq!(info.underlay_index, Some(4));
+        assert_eq!((info.input_key, info.output_key), (Some(7), Some(8)));
+        assert_eq!(info.local, Some(Ipv4Addr::new(192, 0, 2, 1).into()));
+        assert_eq!(info.remote, Some(Ipv4Addr::new(198, 51, 100, 1).into()));
+        assert_eq!((info.ttl, info.tos), (None, None));
+        assert_eq!(info.encap, Some(TunnelEncap::Fou));
+        assert_eq!(info.port, Some(5555));
+        assert!(info.flags.checksum && info.flags.ttl_inherit && info.flags.pmtu_discovery);
+        assert!(!info.flags.sequence);
+
+        let gre6 = link(vec![
+            LinkInfo::Kind(InfoKind::GreTun6),
+            LinkInfo::Data(InfoData::GreTun6(vec![
+                InfoGre6::Other(DefaultNla::new(1, 5u32.to_ne_bytes().to_vec())),
+                InfoGre6::Local(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+                InfoGre6::Remote(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2)),
+                InfoGre6::Ttl(64),
+                InfoGre6::FlowLabel(0x0b81_2345),
+                InfoGre6::Other(DefaultNla::new(18, 0x4u32.to_ne_bytes().to_vec())),
+            ])),
+        ]);
+        let info = tunnel_info(&gre6).expect("gre6 tunnel");
+        assert_eq!(info.underlay_index, Some(5));
+        assert_eq!(
+            info.remote,
+            Some(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2).into())
+        );
+        assert_eq!(
+            (info.ttl, info.tos, info.flowlabel),
+            (Some(64), Some(0xb8), None)
+        );
+
+        let ip6tnl = link(vec![
+            LinkInfo::Kind(InfoKind::Ip6Tnl),
+            LinkInfo::Data(InfoData::IpTunnel(vec![
+                InfoIpTunnel::Link(3),
+                InfoIpTunnel::Remote(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2).into()),
+                InfoIpTunnel::Ttl(64),
+                // The attribute as decoded from its network byte order payload.
+                InfoIpTunnel::FlowInfo(u32::from_ne_bytes(0x0b81_2345u32.to_be_bytes())),
+                InfoIpTunnel::Ipv6Flags(Ip6TunnelFlags::UseOrigTclass),
+            ])),
+        ]);
+        let info = tunnel_info(&ip6tnl).expect("ip6tnl tunnel");
+        assert_eq!(info.underlay_index, Some(3));
+        assert_eq!((info.tos, info.flowlabel), (None, Some(0x12345)));
+
+        let bridge = link(vec![LinkInfo::Kind(InfoKind::Bridge)]);
+        assert_eq!(tunnel_info(&bridge), None);
+    }
+}
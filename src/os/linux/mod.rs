@@ -1,21 +1,43 @@
 pub mod arp;
 #[cfg(not(target_os = "android"))]
+pub mod bond;
+#[cfg(not(target_os = "android"))]
+pub mod bridge;
+#[cfg(not(target_os = "android"))]
 mod dhcp;
 #[cfg(not(target_os = "android"))]
 pub mod flags;
 #[cfg(feature = "gateway")]
 pub(crate) mod gateway;
 #[cfg(not(target_os = "android"))]
+pub mod graph;
+#[cfg(not(target_os = "android"))]
 pub mod interface;
 pub mod ipv6_addr_flags;
+#[cfg(not(target_os = "android"))]
+pub mod linkinfo;
+#[cfg(not(target_os = "android"))]
+#[cfg(feature = "gateway")]
+pub mod monitor;
 pub mod mtu;
 #[cfg(not(target_os = "android"))]
+#[cfg(feature = "gateway")]
+pub mod neighbour;
+#[cfg(not(target_os = "android"))]
 pub mod netlink;
 pub(crate) mod netlink_io;
 #[cfg(not(target_os = "android"))]
 #[cfg(feature = "gateway")]
 pub mod procfs;
 #[cfg(not(target_os = "android"))]
+#[cfg(feature = "gateway")]
+pub mod route;
+#[cfg(not(target_os = "android"))]
 pub mod state;
+pub mod stats;
 #[cfg(not(target_os = "android"))]
 pub mod sysfs;
+#[cfg(not(target_os = "android"))]
+pub mod veth;
+#[cfg(not(target_os = "android"))]
+pub mod wireguard;
@@ -0,0 +1,454 @@
+use super::netlink;
+use crate::interface::state::OperState;
+use crate::monitor::NetworkEvent;
+use crate::route::types::IpFamily;
+use ipnet::IpNet;
+use netlink_packet_core::NetlinkPayload;
+use netlink_packet_route::RouteNetlinkMessage;
+use netlink_packet_route::address::AddressMessage;
+use netlink_packet_route::link::LinkMessage;
+use netlink_packet_route::neighbour::NeighbourMessage;
+use netlink_packet_route::route::RouteMessage;
+use netlink_sys::Socket;
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::net::IpAddr;
+
+// rtnetlink multicast groups (RTNLGRP_*)
+const RTNLGRP_LINK: u32 = 1;
+const RTNLGRP_NEIGH: u32 = 3;
+const RTNLGRP_IPV4_IFADDR: u32 = 5;
+const RTNLGRP_IPV4_ROUTE: u32 = 7;
+const RTNLGRP_IPV6_IFADDR: u32 = 9;
+const RTNLGRP_IPV6_ROUTE: u32 = 11;
+
+type DefaultPath = (Option<IpAddr>, Option<u32>);
+
+/// State needed to turn raw notifications into events.
+#[derive(Default)]
+struct Tracker {
+    names: HashMap<u32, String>,
+    states: HashMap<u32, OperState>,
+    defaults: HashMap<IpFamily, DefaultPath>,
+    // Families whose default route may have changed since the last check
+    dirty: Vec<IpFamily>,
+}
+
+impl Tracker {
+    fn seed_links(&mut self, links: &[LinkMessage]) {
+        self.names.clear();
+        self.states.clear();
+        for link in links {
+            let index = link.header.index;
+            if let Some(name) = netlink::name_from_link(link) {
+                self.names.insert(index, name);
+            }
+            if let Some(state) = netlink::oper_state_from_link(link) {
+                self.states.insert(index, state);
+            }
+        }
+    }
+
+    fn handle(&mut self, msg: RouteNetlinkMessage, out: &mut VecDeque<NetworkEvent>) {
+        match msg {
+            RouteNetlinkMessage::NewLink(link) => self.on_new_link(&link, out),
+            RouteNetlinkMessage::DelLink(link) => {
+                // Routes through a removed link are flushed without RTM_DELROUTE
+                self.mark_all_dirty();
+                let if_index = link.header.index;
+                self.states.remove(&if_index);
+                let if_name = self
+                    .names
+                    .remove(&if_index)
+                    .or_else(|| netlink::name_from_link(&link));
+                out.push_back(NetworkEvent::LinkRemoved { if_index, if_name });
+            }
+            RouteNetlinkMessage::NewAddress(addr) => {
+                if let Some((if_index, addr)) = self.address(&addr) {
+                    out.push_back(NetworkEvent::AddressAdded {
+                        if_index,
+                        if_name: self.names.get(&if_index).cloned(),
+                        addr,
+                    });
+                }
+            }
+            RouteNetlinkMessage::DelAddress(addr) => {
+                // Losing the address flushes the routes that use it as source or gateway
+                self.mark_all_dirty();
+                if let Some((if_index, addr)) = self.address(&addr) {
+                    out.push_back(NetworkEvent::AddressRemoved {
+                        if_index,
+                        if_name: self.names.get(&if_index).cloned(),
+                        addr,
+                    });
+                }
+            }
+            RouteNetlinkMessage::NewRoute(rt) => {
+                if let Some(route) = self.route(&rt) {
+                    out.push_back(NetworkEvent::RouteAdded(route));
+                }
+            }
+            RouteNetlinkMessage::DelRoute(rt) => {
+                if let Some(route) = self.route(&rt) {
+                    out.push_back(NetworkEvent::RouteRemoved(route));
+                }
+            }
+            RouteNetlinkMessage::NewNeighbour(n) => {
+                if let Some(neigh) = self.neighbour(&n) {
+                    out.push_back(NetworkEvent::NeighbourChanged(neigh));
+                }
+            }
+            RouteNetlinkMessage::DelNeighbour(n) => {
+                if let Some(neigh) = self.neighbour(&n) {
+                    out.push_back(NetworkEvent::NeighbourRemoved(neigh));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_new_link(&mut self, link: &LinkMessage, out: &mut VecDeque<NetworkEvent>) {
+        let if_index = link.header.index;
+        if let Some(new_name) = netlink::name_from_link(link) {
+            match self.names.insert(if_index, new_name.clone()) {
+                None => out.push_back(NetworkEvent::LinkAdded {
+                    if_index,
+                    if_name: new_name,
+                }),
+                Some(old_name) if old_name != new_name => {
+                    out.push_back(NetworkEvent::LinkRenamed {
+                        if_index,
+                        old_name,
+                        new_name,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        if let Some(new_state) = netlink::oper_state_from_link(link)
+            && let Some(old_state) = self.states.insert(if_index, new_state)
+            && old_state != new_state
+        {
+            // A link going down flushes its IPv4 routes without RTM_DELROUTE
+            self.mark_all_dirty();
+            out.push_back(NetworkEvent::OperStateChanged {
+                if_index,
+                if_name: self.names.get(&if_index).cloned(),
+                old_state,
+                new_state,
+            });
+        }
+    }
+
+    fn address(&self, addr: &AddressMessage) -> Option<(u32, IpNet)> {
+        let (ip, prefix, _) = netlink::ip_from_addr(addr)?;
+        Some((addr.header.index, IpNet::new(ip, prefix).ok()?))
+    }
+
+    fn route(&mut self, rt: &RouteMessage) -> Option<crate::route::table::Route> {
+        let route = super::route::to_route(netlink::route_extract(rt)?, &self.names)?;
+        if route.is_default() {
+            let family = if route.is_ipv4() {
+                IpFamily::V4
+            } else {
+                IpFamily::V6
+            };
+            self.mark_dirty(family);
+        }
+        Some(route)
+    }
+
+    fn mark_dirty(&mut self, family: IpFamily) {
+        if !self.dirty.contains(&family) {
+            self.dirty.push(family);
+        }
+    }
+
+    fn mark_all_dirty(&mut self) {
+        self.mark_dirty(IpFamily::V4);
+        self.mark_dirty(IpFamily::V6);
+    }
+
+    fn neighbour(&self, n: &NeighbourMessage) -> Option<crate::net::neighbour::Neighbour> {
+        super::neighbour::to_neighbour(netlink::neigh_extract(n), &self.names)
+    }
+
+    /// Records the current default path of `family`, returning an event when it changed.
+    ///
+    /// A failed lookup keeps the previous path and leaves the family dirty, so it is checked
+    /// again after the next notification instead of being reported as removed.
+    fn update_default(
+        &mut self,
+        family: IpFamily,
+        path: io::Result<DefaultPath>,
+    ) -> Option<NetworkEvent> {
+        let Ok(path) = path else {
+            self.mark_dirty(family);
+            return None;
+        };
+        let old = self.defaults.insert(family, path).unwrap_or((None, None));
+        (old != path).then_some(NetworkEvent::DefaultGatewayChanged {
+            family,
+            gateway: path.0,
+            if_index: path.1,
+        })
+    }
+}
+
+fn default_path(family: IpFamily) -> io::Result<DefaultPath> {
+    Ok(match super::route::default_route_for(family)? {
+        Some(rt) => (rt.gateway, Some(rt.if_index)),
+        None => (None, None),
+    })
+}
+
+pub struct RtnlMonitor {
+    sock: Socket,
+    buf: Vec<u8>,
+    tracker: Tracker,
+    pending: VecDeque<NetworkEvent>,
+}
+
+impl RtnlMonitor {
+    pub fn new() -> io::Result<RtnlMonitor> {
+        // Subscribe before taking the snapshot so no change falls between the two.
+        let sock = netlink::open_monitor_socket(&[
+            RTNLGRP_LINK,
+            RTNLGRP_NEIGH,
+            RTNLGRP_IPV4_IFADDR,
+            RTNLGRP_IPV4_ROUTE,
+            RTNLGRP_IPV6_IFADDR,
+            RTNLGRP_IPV6_ROUTE,
+        ])?;
+        let mut monitor = RtnlMonitor {
+            sock,
+            buf: vec![0u8; super::netlink_io::RECV_BUFSZ],
+            tracker: Tracker::default(),
+            pending: VecDeque::new(),
+        };
+        monitor.resync()?;
+        Ok(monitor)
+    }
+
+    fn resync(&mut self) -> io::Result<()> {
+        self.tracker.seed_links(&netlink::dump_links()?);
+        self.tracker.dirty.clear();
+        for family in [IpFamily::V4, IpFamily::V6] {
+            match default_path(family) {
+                Ok(path) => {
+                    self.tracker.defaults.insert(family, path);
+                }
+                Err(_) => self.tracker.mark_dirty(family),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn next_event(&mut self) -> io::Result<NetworkEvent> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+            match super::netlink_io::recv_notifications(&mut self.sock, &mut self.buf) {
+                Ok(msgs) => {
+                    for msg in msgs {
+                        if let NetlinkPayload::InnerMessage(inner) = msg.payload {
+                            self.tracker.handle(inner, &mut self.pending);
+                        }
+                    }
+                    for family in std::mem::take(&mut self.tracker.dirty) {
+                        let path = default_path(family);
+                        if let Some(event) = self.tracker.update_default(family, path) {
+                            self.pending.push_back(event);
+                        }
+                    }
+                }
+                Err(e) if e.raw_os_error() == Some(libc::ENOBUFS) => {
+                    self.resync()?;
+                    self.pending.push_back(NetworkEvent::EventsDropped);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tracker;
+    use crate::interface::state::OperState;
+    use crate::monitor::NetworkEvent;
+    use crate::route::types::IpFamily;
+    use netlink_packet_route::AddressFamily;
+    use netlink_packet_route::RouteNetlinkMessage;
+    use netlink_packet_route::address::{AddressAttribute, AddressMessage};
+    use netlink_packet_route::link::{LinkAttribute, LinkMessage, State};
+    use netlink_packet_route::route::{RouteAddress, RouteAttribute, RouteMessage};
+    use std::collections::VecDeque;
+    use std::io;
+    use std::net::Ipv4Addr;
+
+    fn link(index: u32, name: &str, state: State) -> LinkMessage {
+        let mut link = LinkMessage::default();
+        link.header.index = index;
+        link.attributes = vec![
+            LinkAttribute::IfName(name.to_string()),
+            LinkAttribute::OperState(state),
+        ];
+        link
+    }
+
+    #[test]
+    fn reports_link_lifecycle() {
+        let mut tracker = Tracker::default();
+        let mut out = VecDeque::new();
+
+        tracker.handle(
+            RouteNetlinkMessage::NewLink(link(7, "veth0", State::Down)),
+            &mut out,
+        );
+        tracker.handle(
+            RouteNetlinkMessage::NewLink(link(7, "veth0", State::Up)),
+            &mut out,
+        );
+        tracker.handle(
+            RouteNetlinkMessage::NewLink(link(7, "uplink", State::Up)),
+            &mut out,
+        );
+        tracker.handle(
+            RouteNetlinkMessage::DelLink(link(7, "uplink", State::Up)),
+            &mut out,
+        );
+
+        let events: Vec<_> = out.into_iter().collect();
+        assert_eq!(
+            events,
+            vec![
+                NetworkEvent::LinkAdded {
+                    if_index: 7,
+                    if_name: "veth0".into(),
+                },
+                NetworkEvent::OperStateChanged {
+                    if_index: 7,
+                    if_name: Some("veth0".into()),
+                    old_state: OperState::Down,
+                    new_state: OperState::Up,
+                },
+                NetworkEvent::LinkRenamed {
+                    if_index: 7,
+                    old_name: "veth0".into(),
+                    new_name: "uplink".into(),
+                },
+                NetworkEvent::LinkRemoved {
+                    if_index: 7,
+                    if_name: Some("uplink".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_addresses_with_interface_names() {
+        let mut tracker = Tracker::default();
+        tracker.seed_links(&[link(3, "eth1", State::Up)]);
+        let mut addr = AddressMessage::default();
+        addr.header.index = 3;
+        addr.header.prefix_len = 24;
+        addr.attributes = vec![AddressAttribute::Local(Ipv4Addr::new(10, 0, 0, 5).into())];
+        let mut out = VecDeque::new();
+
+        tracker.handle(RouteNetlinkMessage::DelAddress(addr), &mut out);
+
+        assert_eq!(
+            out.pop_front(),
+            Some(NetworkEvent::AddressRemoved {
+                if_index: 3,
+                if_name: Some("eth1".into()),
+                addr: "10.0.0.5/24".parse().unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn marks_families_dirty_on_silent_route_flushes() {
+        let mut tracker = Tracker::default();
+        tracker.seed_links(&[link(2, "eth0", State::Up)]);
+        let mut out = VecDeque::new();
+
+        tracker.handle(
+            RouteNetlinkMessage::NewLink(link(2, "eth0", State::Up)),
+            &mut out,
+        );
+        assert!(tracker.dirty.is_empty());
+
+        tracker.handle(
+            RouteNetlinkMessage::NewLink(link(2, "eth0", State::Down)),
+            &mut out,
+        );
+        assert_eq!(tracker.dirty, vec![IpFamily::V4, IpFamily::V6]);
+
+        tracker.dirty.clear();
+        let mut addr = AddressMessage::default();
+        addr.header.index = 2;
+        addr.header.prefix_len = 24;
+        addr.attributes = vec![AddressAttribute::Local(Ipv4Addr::new(192, 0, 2, 2).into())];
+        tracker.handle(RouteNetlinkMessage::DelAddress(addr), &mut out);
+        assert_eq!(tracker.dirty, vec![IpFamily::V4, IpFamily::V6]);
+    }
+
+    #[test]
+    fn marks_family_dirty_on_default_route_changes() {
+        let mut tracker = Tracker::default();
+        let mut rt = RouteMessage::default();
+        rt.header.address_family = AddressFamily::Inet;
+        rt.header.table = 254;
+        rt.attributes = vec![
+            RouteAttribute::Gateway(RouteAddress::Inet(Ipv4Addr::new(192, 0, 2, 1))),
+            RouteAttribute::Oif(2),
+        ];
+        let mut out = VecDeque::new();
+
+        tracker.handle(RouteNetlinkMessage::NewRoute(rt), &mut out);
+
+        assert!(matches!(out.pop_front(), Some(NetworkEvent::RouteAdded(r)) if r.is_default()));
+        assert_eq!(tracker.dirty, vec![IpFamily::V4]);
+
+        let gw = Some(Ipv4Addr::new(192, 0, 2, 1).into());
+        assert!(
+            tracker
+                .update_default(IpFamily::V4, Ok((gw, Some(2))))
+                .is_some()
+        );
+        assert!(
+            tracker
+                .update_default(IpFamily::V4, Ok((gw, Some(2))))
+                .is_none()
+        );
+        assert_eq!(
+            tracker.update_default(IpFamily::V4, Ok((None, None))),
+            Some(NetworkEvent::DefaultGatewayChanged {
+                family: IpFamily::V4,
+                gateway: None,
+                if_index: None,
+            })
+        );
+    }
+
+    #[test]
+    fn keeps_default_path_when_lookup_fails() {
+        let mut tracker = Tracker::default();
+        let gw = Some(Ipv4Addr::new(192, 0, 2, 1).into());
+        tracker.update_default(IpFamily::V4, Ok((gw, Some(2))));
+
+        let failure = io::Error::from_raw_os_error(libc::ENOBUFS);
+        assert_eq!(tracker.update_default(IpFamily::V4, Err(failure)), None);
+        assert_eq!(tracker.defaults[&IpFamily::V4], (gw, Some(2)));
+        assert_eq!(tracker.dirty, vec![IpFamily::V4]);
+
+        // The next successful lookup finds the path unchanged.
+        assert_eq!(
+            tracker.update_default(IpFamily::V4, Ok((gw, Some(2)))),
+            None
+        );
+    }
+}
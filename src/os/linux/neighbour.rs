@@ -0,0 +1,39 @@
+use super::netlink::{self, NeighRow, clock_ticks_to_duration};
+use crate::net::mac::MacAddr;
+use crate::net::neighbour::Neighbour;
+use std::collections::HashMap;
+use std::io;
+
+const NTF_ROUTER: u8 = 0x80;
+
+pub(crate) fn to_neighbour(r: NeighRow, names: &HashMap<u32, String>) -> Option<Neighbour> {
+    // Entries without a protocol address (e.g. bridge FDB entries) are skipped.
+    let ip_addr = r.ip?;
+    let ages = r.cache_info.map(|(c, u, up)| {
+        (
+            clock_ticks_to_duration(c.into()),
+            clock_ticks_to_duration(u.into()),
+            clock_ticks_to_duration(up.into()),
+        )
+    });
+    Some(Neighbour {
+        ip_addr,
+        mac_addr: r.mac.map(MacAddr::from_octets),
+        if_index: r.ifindex,
+        if_name: names.get(&r.ifindex).cloned(),
+        state: r.state.into(),
+        is_router: r.flags & NTF_ROUTER != 0,
+        confirmed: ages.map(|a| a.0),
+        used: ages.map(|a| a.1),
+        updated: ages.map(|a| a.2),
+    })
+}
+
+pub fn neighbours() -> io::Result<Vec<Neighbour>> {
+    let rows = netlink::collect_neigh_rows()?;
+    let names = netlink::collect_link_names().unwrap_or_default();
+    Ok(rows
+        .into_iter()
+        .filter_map(|r| to_neighbour(r, &names))
+        .collect())
+}
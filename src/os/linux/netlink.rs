@@ -1,21 +1,49 @@
-use netlink_packet_core::NetlinkPayload;
+use crate::interface::kind::LinkKind;
+use crate::interface::state::OperState;
+use crate::interface::tunnel::TunnelInfo;
+use crate::stats::counters::InterfaceStats;
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST, NetlinkPayload, NlasIterator};
 use netlink_packet_route::{
     RouteNetlinkMessage,
     address::{AddressAttribute, AddressFlags, AddressMessage},
-    link::{LinkAttribute, LinkMessage},
+    link::{LinkAttribute, LinkMessage, State},
+    neighbour,
+};
+use netlink_sys::{
+    Socket,
+    protocols::{NETLINK_GENERIC, NETLINK_ROUTE},
 };
-use netlink_sys::{Socket, protocols::NETLINK_ROUTE};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
 use std::{collections::HashMap, io};
 
+#[cfg(feature = "gateway")]
+use crate::route::lookup::RouteQuery;
 #[cfg(feature = "gateway")]
 use netlink_packet_route::AddressFamily;
 #[cfg(feature = "gateway")]
 use netlink_packet_route::neighbour::{NeighbourAddress, NeighbourAttribute, NeighbourMessage};
 #[cfg(feature = "gateway")]
-use netlink_packet_route::route::{RouteAddress, RouteAttribute, RouteMessage};
+use netlink_packet_route::route::{
+    RouteAddress, RouteAttribute, RouteFlags, RouteMessage, RouteMetric, RouteNextHop, RouteVia,
+};
+#[cfg(feature = "gateway")]
+use netlink_packet_route::rule::RuleMessage;
 
 const SEQ_BASE: u32 = 0x6E_64_65_76; // "ndev"
+const RTM_NEWMDB: u16 = 84;
+const RTM_GETMDB: u16 = 86;
+// Generic netlink controller and WireGuard family, from linux/genetlink.h and
+// linux/wireguard.h.
+const GENL_ID_CTRL: u16 = 0x10;
+const GENL_HDRLEN: usize = 4;
+const CTRL_CMD_GETFAMILY: u8 = 3;
+const CTRL_ATTR_FAMILY_ID: u16 = 1;
+const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+const WG_GENL_NAME: &str = "wireguard";
+const WG_GENL_VERSION: u8 = 1;
+const WG_CMD_GET_DEVICE: u8 = 0;
+const WGDEVICE_A_IFINDEX: u16 = 1;
 
 fn open_route_socket() -> io::Result<Socket> {
     let mut sock = Socket::new(NETLINK_ROUTE)
@@ -26,6 +54,31 @@ fn open_route_socket() -> io::Result<Socket> {
     Ok(sock)
 }
 
+fn open_generic_socket() -> io::Result<Socket> {
+    let mut sock =
+        Socket::new(NETLINK_GENERIC).map_err(|e| io::Error::other(format!("netlink open: {e}")))?;
+    sock.bind_auto()
+        .map_err(|e| io::Error::other(format!("bind_auto: {e}")))?;
+    super::netlink_io::set_non_blocking(&sock)?;
+    Ok(sock)
+}
+
+/// Opens a blocking socket subscribed to rtnetlink notification groups.
+#[cfg(feature = "gateway")]
+pub fn open_monitor_socket(groups: &[u32]) -> io::Result<Socket> {
+    let mut sock =
+        Socket::new(NETLINK_ROUTE).map_err(|e| io::Error::other(format!("netlink open: {e}")))?;
+    sock.bind_auto()
+        .map_err(|e| io::Error::other(format!("bind_auto: {e}")))?;
+    // Bursts such as an interface going down produce many notifications at once.
+    let _ = sock.set_rx_buf_sz(super::netlink_io::RECV_BUFSZ);
+    for group in groups {
+        sock.add_membership(*group)
+            .map_err(|e| io::Error::other(format!("netlink add_membership({group}): {e}")))?;
+    }
+    Ok(sock)
+}
+
 pub fn dump_links() -> io::Result<Vec<LinkMessage>> {
     let mut sock = open_route_socket()?;
     let seq = SEQ_BASE ^ 0x01;
@@ -44,6 +97,107 @@ pub fn dump_links() -> io::Result<Vec<LinkMessage>> {
     Ok(out)
 }
 
+/// Dumps the forwarding databases of bridges and VXLAN devices (`AF_BRIDGE` neighbours).
+pub fn dump_bridge_fdb() -> io::Result<Vec<neighbour::NeighbourMessage>> {
+    let mut sock = open_route_socket()?;
+    let seq = SEQ_BASE ^ 0x09;
+    let mut req = neighbour::NeighbourMessage::default();
+    req.header.family = netlink_packet_route::AddressFamily::Bridge;
+    super::netlink_io::send_dump(&mut sock, RouteNetlinkMessage::GetNeighbour(req), seq)?;
+    let msgs = super::netlink_io::recv_multi(&mut sock, seq)?;
+    let mut out = Vec::new();
+    for m in msgs {
+        if let NetlinkPayload::InnerMessage(RouteNetlinkMessage::NewNeighbour(n)) = m.payload {
+            out.push(n);
+        }
+    }
+    Ok(out)
+}
+
+/// Dumps the bridge multicast databases. Each message carries the `struct br_port_msg`
+/// header and the `MDBA_*` attributes of one bridge.
+pub fn dump_mdb() -> io::Result<Vec<Vec<u8>>> {
+    let mut sock = open_route_socket()?;
+    let seq = SEQ_BASE ^ 0x0A;
+    // struct br_port_msg { __u8 family; __u32 ifindex; }
+    let mut req = [0u8; 8];
+    req[0] = libc::AF_BRIDGE as u8;
+    super::netlink_io::send_raw(&mut sock, RTM_GETMDB, NLM_F_REQUEST | NLM_F_DUMP, seq, &req)?;
+    Ok(super::netlink_io::recv_raw_multi(&mut sock, seq)?
+        .into_iter()
+        .filter(|m| m.message_type == RTM_NEWMDB)
+        .map(|m| m.payload)
+        .collect())
+}
+
+/// Builds a generic netlink request: a `struct genlmsghdr` followed by one attribute.
+fn genl_request(cmd: u8, version: u8, attr: u16, value: &[u8]) -> Vec<u8> {
+    let len = 4 + value.len();
+    let mut req = Vec::with_capacity(GENL_HDRLEN + len.next_multiple_of(4));
+    req.extend_from_slice(&[cmd, version, 0, 0]);
+    req.extend_from_slice(&(len as u16).to_ne_bytes());
+    req.extend_from_slice(&attr.to_ne_bytes());
+    req.extend_from_slice(value);
+    req.resize(GENL_HDRLEN + len.next_multiple_of(4), 0);
+    req
+}
+
+/// Resolves the id of a generic netlink family. Returns `None` when the family is not
+/// registered, usually because its kernel module is not loaded.
+fn genl_family_id(sock: &mut Socket, name: &str) -> io::Result<Option<u16>> {
+    let seq = SEQ_BASE ^ 0x0B;
+    let mut family = name.as_bytes().to_vec();
+    family.push(0);
+    let req = genl_request(CTRL_CMD_GETFAMILY, 1, CTRL_ATTR_FAMILY_NAME, &family);
+    super::netlink_io::send_raw(sock, GENL_ID_CTRL, NLM_F_REQUEST, seq, &req)?;
+    let reply = match super::netlink_io::recv_raw_reply(sock, seq) {
+        Ok(reply) => reply,
+        Err(e) if e.raw_os_error() == Some(libc::ENOENT) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let attrs = reply.payload.get(GENL_HDRLEN..).unwrap_or_default();
+    Ok(NlasIterator::new(attrs)
+        .map_while(Result::ok)
+        .find(|nla| nla.kind() == CTRL_ATTR_FAMILY_ID)
+        .and_then(|nla| <[u8; 2]>::try_from(nla.value()).ok())
+        .map(u16::from_ne_bytes))
+}
+
+/// Dumps the WireGuard configuration of each interface in `indices`.
+///
+/// The kernel splits a device over several messages when its peers do not fit in one. Each
+/// message is returned without its generic netlink header. Interfaces removed since `indices`
+/// was collected are skipped.
+pub fn dump_wireguard(indices: &[u32]) -> io::Result<Vec<Vec<Vec<u8>>>> {
+    let mut sock = open_generic_socket()?;
+    let Some(family) = genl_family_id(&mut sock, WG_GENL_NAME)? else {
+        return Ok(Vec::new());
+    };
+    let seq = SEQ_BASE ^ 0x0C;
+    let mut out = Vec::new();
+    for index in indices {
+        let req = genl_request(
+            WG_CMD_GET_DEVICE,
+            WG_GENL_VERSION,
+            WGDEVICE_A_IFINDEX,
+            &index.to_ne_bytes(),
+        );
+        super::netlink_io::send_raw(&mut sock, family, NLM_F_REQUEST | NLM_F_DUMP, seq, &req)?;
+        let msgs = match super::netlink_io::recv_raw_multi(&mut sock, seq) {
+            Ok(msgs) => msgs,
+            Err(e) if e.raw_os_error() == Some(libc::ENODEV) => continue,
+            Err(e) => return Err(e),
+        };
+        out.push(
+            msgs.into_iter()
+                .filter(|m| m.message_type == family)
+                .filter_map(|m| m.payload.get(GENL_HDRLEN..).map(<[u8]>::to_vec))
+                .collect(),
+        );
+    }
+    Ok(out)
+}
+
 pub fn dump_addrs() -> io::Result<Vec<AddressMessage>> {
     let mut sock = open_route_socket()?;
     let seq = SEQ_BASE ^ 0x02;
@@ -64,11 +218,33 @@ pub fn dump_addrs() -> io::Result<Vec<AddressMessage>> {
 
 #[cfg(feature = "gateway")]
 pub fn dump_routes() -> io::Result<Vec<RouteMessage>> {
+    dump_routes_of(None)
+}
+
+/// Builds a route dump request, restricted to routes leaving through `oif` when it is set.
+#[cfg(feature = "gateway")]
+fn route_dump_request(oif: Option<u32>) -> RouteMessage {
+    let mut req = RouteMessage::default();
+    if let Some(oif) = oif {
+        req.attributes.push(RouteAttribute::Oif(oif));
+    }
+    req
+}
+
+/// Dumps the routes, or only those leaving through `oif` when it is set.
+///
+/// The kernel filters the dump when strict checking is available (Linux 4.20 and later).
+/// Older kernels return every route, so callers must filter the result as well.
+#[cfg(feature = "gateway")]
+fn dump_routes_of(oif: Option<u32>) -> io::Result<Vec<RouteMessage>> {
     let mut sock = open_route_socket()?;
+    if oif.is_some() {
+        let _ = sock.set_netlink_get_strict_chk(true);
+    }
     let seq = SEQ_BASE ^ 0x03;
     super::netlink_io::send_dump(
         &mut sock,
-        RouteNetlinkMessage::GetRoute(RouteMessage::default()),
+        RouteNetlinkMessage::GetRoute(route_dump_request(oif)),
         seq,
     )?;
     let msgs = super::netlink_io::recv_multi(&mut sock, seq)?;
@@ -83,11 +259,35 @@ pub fn dump_routes() -> io::Result<Vec<RouteMessage>> {
 
 #[cfg(feature = "gateway")]
 pub fn dump_neigh() -> io::Result<Vec<NeighbourMessage>> {
+    dump_neigh_of(None)
+}
+
+/// Builds a neighbour dump request, restricted to entries of `ifindex` when it is set.
+///
+/// The kernel reads the filter from `NDA_IFINDEX` and rejects a non-zero `ndm_ifindex` in
+/// strict mode.
+#[cfg(feature = "gateway")]
+fn neigh_dump_request(ifindex: Option<u32>) -> NeighbourMessage {
+    let mut req = NeighbourMessage::default();
+    if let Some(ifindex) = ifindex {
+        req.attributes.push(NeighbourAttribute::IfIndex(ifindex));
+    }
+    req
+}
+
+/// Dumps the neighbour entries, or only those of `ifindex` when it is set.
+///
+/// Like `dump_routes_of`, the result is unfiltered on kernels without strict checking.
+#[cfg(feature = "gateway")]
+fn dump_neigh_of(ifindex: Option<u32>) -> io::Result<Vec<NeighbourMessage>> {
     let mut sock = open_route_socket()?;
+    if ifindex.is_some() {
+        let _ = sock.set_netlink_get_strict_chk(true);
+    }
     let seq = SEQ_BASE ^ 0x04;
     super::netlink_io::send_dump(
         &mut sock,
-        RouteNetlinkMessage::GetNeighbour(NeighbourMessage::default()),
+        RouteNetlinkMessage::GetNeighbour(neigh_dump_request(ifindex)),
         seq,
     )?;
     let msgs = super::netlink_io::recv_multi(&mut sock, seq)?;
@@ -100,7 +300,26 @@ pub fn dump_neigh() -> io::Result<Vec<NeighbourMessage>> {
     Ok(out)
 }
 
-fn mac_from_link(link: &LinkMessage) -> Option<[u8; 6]> {
+#[cfg(feature = "gateway")]
+pub fn dump_rules() -> io::Result<Vec<RuleMessage>> {
+    let mut sock = open_route_socket()?;
+    let seq = SEQ_BASE ^ 0x06;
+    super::netlink_io::send_dump(
+        &mut sock,
+        RouteNetlinkMessage::GetRule(RuleMessage::default()),
+        seq,
+    )?;
+    let msgs = super::netlink_io::recv_multi(&mut sock, seq)?;
+    let mut out = Vec::new();
+    for m in msgs {
+        if let NetlinkPayload::InnerMessage(RouteNetlinkMessage::NewRule(r)) = m.payload {
+            out.push(r);
+        }
+    }
+    Ok(out)
+}
+
+pub fn mac_from_link(link: &LinkMessage) -> Option<[u8; 6]> {
     for nla in &link.attributes {
         if let LinkAttribute::Address(bytes) = nla {
             if bytes.len() == 6 {
@@ -111,7 +330,14 @@ fn mac_from_link(link: &LinkMessage) -> Option<[u8; 6]> {
     None
 }
 
-fn name_from_link(link: &LinkMessage) -> Option<String> {
+/// Converts a kernel `clock_t` value, in `USER_HZ` ticks, to a duration.
+pub fn clock_ticks_to_duration(ticks: u64) -> Duration {
+    let hz = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    let hz = if hz > 0 { hz as u64 } else { 100 };
+    Duration::from_millis(ticks * 1000 / hz)
+}
+
+pub fn name_from_link(link: &LinkMessage) -> Option<String> {
     for nla in &link.attributes {
         if let LinkAttribute::IfName(n) = nla {
             return Some(n.clone());
@@ -120,7 +346,36 @@ fn name_from_link(link: &LinkMessage) -> Option<String> {
     None
 }
 
-fn ip_from_addr(addr: &AddressMessage) -> Option<(IpAddr, u8, u32)> {
+pub fn oper_state_from_link(link: &LinkMessage) -> Option<OperState> {
+    for nla in &link.attributes {
+        if let LinkAttribute::OperState(state) = nla {
+            return Some(match state {
+                State::NotPresent => OperState::NotPresent,
+                State::Down => OperState::Down,
+                State::LowerLayerDown => OperState::LowerLayerDown,
+                State::Testing => OperState::Testing,
+                State::Dormant => OperState::Dormant,
+                State::Up => OperState::Up,
+                _ => OperState::Unknown,
+            });
+        }
+    }
+    None
+}
+
+#[cfg(feature = "gateway")]
+pub fn collect_link_names() -> io::Result<HashMap<u32, String>> {
+    let links = dump_links()?;
+    let mut names = HashMap::new();
+    for l in links {
+        if let Some(name) = name_from_link(&l) {
+            names.insert(l.header.index, name);
+        }
+    }
+    Ok(names)
+}
+
+pub fn ip_from_addr(addr: &AddressMessage) -> Option<(IpAddr, u8, u32)> {
     let pfx = addr.header.prefix_len;
     let mut ip_out = None;
     let mut flags: Option<AddressFlags> = None;
@@ -151,24 +406,97 @@ fn route_addr_to_ip(a: &RouteAddress) -> Option<IpAddr> {
 }
 
 #[cfg(feature = "gateway")]
-fn route_extract(rt: &RouteMessage) -> (Option<IpAddr>, Option<u8>, Option<IpAddr>, Option<u32>) {
-    // (dst, prefix, gateway, oif)
+#[derive(Debug, Clone)]
+pub struct RouteRow {
+    pub dst: IpAddr,
+    pub prefix: u8,
+    pub gateway: Option<IpAddr>,
+    pub oif: Option<u32>,
+    pub priority: Option<u32>,
+    pub table: u32,
+    pub protocol: u8,
+    pub scope: u8,
+    pub kind: u8,
+    pub pref_src: Option<IpAddr>,
+    pub mtu: Option<u32>,
+    pub nexthops: Vec<NextHopRow>,
+}
+
+#[cfg(feature = "gateway")]
+#[derive(Debug, Clone)]
+pub struct NextHopRow {
+    pub gateway: Option<IpAddr>,
+    pub oif: u32,
+    pub weight: u16,
+    pub flags: u8,
+}
+
+#[cfg(feature = "gateway")]
+fn route_via_to_ip(v: &RouteVia) -> Option<IpAddr> {
+    match v {
+        RouteVia::Inet(v4) => Some(IpAddr::V4(*v4)),
+        RouteVia::Inet6(v6) => Some(IpAddr::V6(*v6)),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "gateway")]
+fn nexthop_extract(nh: &RouteNextHop) -> NextHopRow {
+    let mut gateway = None;
+    for nla in &nh.attributes {
+        match nla {
+            RouteAttribute::Gateway(a) => gateway = route_addr_to_ip(a),
+            // IPv4 routes may use an IPv6 next hop (RFC 5549)
+            RouteAttribute::Via(v) => gateway = route_via_to_ip(v),
+            _ => {}
+        }
+    }
+    NextHopRow {
+        gateway,
+        oif: nh.interface_index,
+        // rtnh_hops holds the weight minus one
+        weight: nh.hops as u16 + 1,
+        flags: nh.flags.bits(),
+    }
+}
+
+#[cfg(feature = "gateway")]
+pub fn route_extract(rt: &RouteMessage) -> Option<RouteRow> {
     let mut dst: Option<IpAddr> = None;
-    let pfx: Option<u8> = Some(rt.header.destination_prefix_length);
-    let mut gw: Option<IpAddr> = None;
+    let mut gateway: Option<IpAddr> = None;
     let mut oif: Option<u32> = None;
+    let mut priority: Option<u32> = None;
+    let mut table = rt.header.table as u32;
+    let mut pref_src: Option<IpAddr> = None;
+    let mut mtu: Option<u32> = None;
+    let mut nexthops: Vec<NextHopRow> = Vec::new();
 
     for nla in &rt.attributes {
         match nla {
             RouteAttribute::Destination(a) => dst = route_addr_to_ip(a),
-            RouteAttribute::Gateway(a) => gw = route_addr_to_ip(a),
+            RouteAttribute::Gateway(a) => gateway = route_addr_to_ip(a),
+            RouteAttribute::Via(v) => gateway = route_via_to_ip(v),
+            RouteAttribute::MultiPath(hops) => {
+                nexthops = hops.iter().map(nexthop_extract).collect();
+            }
+            RouteAttribute::PrefSource(a) => pref_src = route_addr_to_ip(a),
             RouteAttribute::Oif(i) => oif = Some(*i),
+            RouteAttribute::Priority(p) => priority = Some(*p),
+            // RTA_TABLE carries ids that do not fit into the header field
+            RouteAttribute::Table(t) => table = *t,
+            RouteAttribute::Metrics(metrics) => {
+                for m in metrics {
+                    if let RouteMetric::Mtu(v) = m {
+                        mtu = Some(*v);
+                    }
+                }
+            }
             _ => {}
         }
     }
 
-    // if dst is None and pfx is 0, it means default route
-    if dst.is_none() && pfx == Some(0) {
+    // if dst is None, it means default route
+    if dst.is_none() {
         dst = match rt.header.address_family {
             AddressFamily::Inet => Some(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
             AddressFamily::Inet6 => Some(IpAddr::V6(Ipv6Addr::UNSPECIFIED)),
@@ -176,7 +504,66 @@ fn route_extract(rt: &RouteMessage) -> (Option<IpAddr>, Option<u8>, Option<IpAdd
         };
     }
 
-    (dst, pfx, gw, oif)
+    Some(RouteRow {
+        dst: dst?,
+        prefix: rt.header.destination_prefix_length,
+        gateway,
+        oif,
+        priority,
+        table,
+        protocol: rt.header.protocol.into(),
+        scope: rt.header.scope.into(),
+        kind: rt.header.kind.into(),
+        pref_src,
+        mtu,
+        nexthops,
+    })
+}
+
+#[cfg(feature = "gateway")]
+pub fn get_route(query: &RouteQuery) -> io::Result<RouteRow> {
+    let mut rt = RouteMessage::default();
+    let (family, host_len) = match query.destination {
+        IpAddr::V4(_) => (AddressFamily::Inet, 32),
+        IpAddr::V6(_) => (AddressFamily::Inet6, 128),
+    };
+    rt.header.address_family = family;
+    rt.header.destination_prefix_length = host_len;
+    // Report the table that was selected by the lookup instead of RT_TABLE_MAIN
+    rt.header.flags = RouteFlags::LookupTable;
+    rt.attributes
+        .push(RouteAttribute::Destination(query.destination.into()));
+    if let Some(src) = query.source {
+        rt.header.source_prefix_length = host_len;
+        rt.attributes.push(RouteAttribute::Source(src.into()));
+    }
+    if let Some(mark) = query.mark {
+        rt.attributes.push(RouteAttribute::Mark(mark));
+    }
+    if let Some(iif) = query.input_if {
+        rt.attributes.push(RouteAttribute::Iif(iif));
+    }
+    if let Some(oif) = query.output_if {
+        rt.attributes.push(RouteAttribute::Oif(oif));
+    }
+
+    let mut sock = open_route_socket()?;
+    let seq = SEQ_BASE ^ 0x05;
+    super::netlink_io::send_request(&mut sock, RouteNetlinkMessage::GetRoute(rt), seq)?;
+    let reply = super::netlink_io::recv_reply(&mut sock, seq)?;
+    match reply.payload {
+        NetlinkPayload::InnerMessage(RouteNetlinkMessage::NewRoute(rt)) => route_extract(&rt)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unexpected route family")),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unexpected reply to route lookup",
+        )),
+    }
+}
+
+#[cfg(feature = "gateway")]
+pub fn collect_route_rows() -> io::Result<Vec<RouteRow>> {
+    Ok(dump_routes()?.iter().filter_map(route_extract).collect())
 }
 
 #[cfg(feature = "gateway")]
@@ -190,27 +577,52 @@ fn neigh_addr_to_ip(a: &NeighbourAddress) -> Option<IpAddr> {
 }
 
 #[cfg(feature = "gateway")]
-fn neigh_extract(n: &NeighbourMessage) -> (Option<IpAddr>, Option<[u8; 6]>, Option<u32>) {
-    let mut ip = None;
-    let mut mac = None;
-    let mut oif = Some(n.header.ifindex as u32);
+#[derive(Debug, Clone)]
+pub struct NeighRow {
+    pub ip: Option<IpAddr>,
+    pub mac: Option<[u8; 6]>,
+    pub ifindex: u32,
+    pub state: u16,
+    pub flags: u8,
+    /// `(confirmed, used, updated)` ages in clock ticks.
+    pub cache_info: Option<(u32, u32, u32)>,
+}
+
+#[cfg(feature = "gateway")]
+pub fn neigh_extract(n: &NeighbourMessage) -> NeighRow {
+    let mut row = NeighRow {
+        ip: None,
+        mac: None,
+        ifindex: n.header.ifindex,
+        state: n.header.state.into(),
+        flags: n.header.flags.bits(),
+        cache_info: None,
+    };
 
     for nla in &n.attributes {
         match nla {
             NeighbourAttribute::Destination(a) => {
-                ip = neigh_addr_to_ip(a);
+                row.ip = neigh_addr_to_ip(a);
             }
             // Link-layer address (MAC)
             NeighbourAttribute::LinkLayerAddress(v) => {
                 if v.len() == 6 {
-                    mac = Some([v[0], v[1], v[2], v[3], v[4], v[5]]);
+                    row.mac = Some([v[0], v[1], v[2], v[3], v[4], v[5]]);
                 }
             }
-            NeighbourAttribute::IfIndex(i) => oif = Some(*i as u32),
+            NeighbourAttribute::IfIndex(i) => row.ifindex = *i,
+            NeighbourAttribute::CacheInfo(ci) => {
+                row.cache_info = Some((ci.confirmed, ci.used, ci.updated));
+            }
             _ => {}
         }
     }
-    (ip, mac, oif)
+    row
+}
+
+#[cfg(feature = "gateway")]
+pub fn collect_neigh_rows() -> io::Result<Vec<NeighRow>> {
+    Ok(dump_neigh()?.iter().map(neigh_extract).collect())
 }
 
 fn mtu_from_link(link: &LinkMessage) -> Option<u32> {
@@ -232,6 +644,42 @@ pub struct IfRow {
     pub ipv6_addr_flags: Vec<u32>,
     pub flags: u32,
     pub mtu: Option<u32>,
+    pub link_type: u16,
+    pub stats: Option<InterfaceStats>,
+    pub oper_state: Option<OperState>,
+    pub kind: Option<LinkKind>,
+    pub tunnel: Option<TunnelInfo>,
+}
+
+fn if_row_from_link(l: &LinkMessage) -> IfRow {
+    let idx = l.header.index;
+    IfRow {
+        index: idx,
+        name: name_from_link(l).unwrap_or_else(|| idx.to_string()),
+        mac: mac_from_link(l),
+        ipv4: vec![],
+        ipv6: vec![],
+        ipv6_addr_flags: vec![],
+        flags: l.header.flags.bits(),
+        mtu: mtu_from_link(l),
+        link_type: l.header.link_layer_type.into(),
+        stats: super::stats::stats_from_link(l),
+        oper_state: oper_state_from_link(l),
+        kind: super::linkinfo::link_kind(l),
+        tunnel: super::linkinfo::tunnel_info(l),
+    }
+}
+
+fn push_addr(row: &mut IfRow, a: &AddressMessage) {
+    if let Some((ip, pfx, addr_flags)) = ip_from_addr(a) {
+        match ip {
+            IpAddr::V4(v4) => row.ipv4.push((v4, pfx)),
+            IpAddr::V6(v6) => {
+                row.ipv6.push((v6, pfx));
+                row.ipv6_addr_flags.push(addr_flags);
+            }
+        }
+    }
 }
 
 pub fn collect_interfaces() -> io::Result<Vec<IfRow>> {
@@ -240,44 +688,83 @@ pub fn collect_interfaces() -> io::Result<Vec<IfRow>> {
 
     let mut base: HashMap<u32, IfRow> = HashMap::new();
     for l in links {
-        let idx = l.header.index as u32;
-        let name = name_from_link(&l).unwrap_or_else(|| idx.to_string());
-        let mac = mac_from_link(&l);
-        let flags = l.header.flags.bits();
-        let mtu_nl = mtu_from_link(&l);
-        base.insert(
-            idx,
-            IfRow {
-                index: idx,
-                name,
-                mac,
-                ipv4: vec![],
-                ipv6: vec![],
-                ipv6_addr_flags: vec![],
-                flags,
-                mtu: mtu_nl,
-            },
-        );
+        base.insert(l.header.index, if_row_from_link(&l));
     }
 
     for a in addrs {
-        let idx = a.header.index as u32;
-        if let Some((ip, pfx, addr_flags)) = ip_from_addr(&a) {
-            if let Some(row) = base.get_mut(&idx) {
-                match ip {
-                    IpAddr::V4(v4) => row.ipv4.push((v4, pfx)),
-                    IpAddr::V6(v6) => {
-                        row.ipv6.push((v6, pfx));
-                        row.ipv6_addr_flags.push(addr_flags);
-                    }
-                }
-            }
+        if let Some(row) = base.get_mut(&a.header.index) {
+            push_addr(row, &a);
         }
     }
 
     Ok(base.into_values().collect())
 }
 
+/// Identifies the link requested by `get_link`.
+pub enum LinkSelector<'a> {
+    Index(u32),
+    Name(&'a str),
+    /// An index in the network namespace with the given id, as in `IFLA_LINK_NETNSID`.
+    /// Requires Linux 4.15 or later.
+    IndexInNetns(u32, i32),
+}
+
+/// Requests a single link from the kernel. Returns `None` when no such link exists.
+pub fn get_link(selector: LinkSelector<'_>) -> io::Result<Option<LinkMessage>> {
+    let mut sock = open_route_socket()?;
+    let seq = SEQ_BASE ^ 0x07;
+    let mut req = LinkMessage::default();
+    match selector {
+        LinkSelector::Index(index) => req.header.index = index,
+        LinkSelector::Name(name) => req.attributes.push(LinkAttribute::IfName(name.to_string())),
+        LinkSelector::IndexInNetns(index, netns_id) => {
+            req.header.index = index;
+            req.attributes.push(LinkAttribute::IfNetnsId(netns_id));
+        }
+    }
+    super::netlink_io::send_request(&mut sock, RouteNetlinkMessage::GetLink(req), seq)?;
+    match super::netlink_io::recv_reply(&mut sock, seq) {
+        Ok(m) => match m.payload {
+            NetlinkPayload::InnerMessage(RouteNetlinkMessage::NewLink(link)) => Ok(Some(link)),
+            _ => Ok(None),
+        },
+        Err(e) if e.raw_os_error() == Some(libc::ENODEV) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Dumps the addresses of one interface.
+///
+/// The kernel filters the dump when strict checking is available (Linux 4.20 and later).
+/// Older kernels return every address, so the result is filtered here as well.
+pub fn dump_addrs_of(index: u32) -> io::Result<Vec<AddressMessage>> {
+    let mut sock = open_route_socket()?;
+    let _ = sock.set_netlink_get_strict_chk(true);
+    let seq = SEQ_BASE ^ 0x08;
+    let mut req = AddressMessage::default();
+    req.header.index = index;
+    super::netlink_io::send_dump(&mut sock, RouteNetlinkMessage::GetAddress(req), seq)?;
+    let msgs = super::netlink_io::recv_multi(&mut sock, seq)?;
+    let mut out = Vec::new();
+    for m in msgs {
+        if let NetlinkPayload::InnerMessage(RouteNetlinkMessage::NewAddress(addr)) = m.payload
+            && addr.header.index == index
+        {
+            out.push(addr);
+        }
+    }
+    Ok(out)
+}
+
+/// Builds the row of a single link, fetching only its addresses.
+pub fn collect_interface(link: &LinkMessage) -> io::Result<IfRow> {
+    let mut row = if_row_from_link(link);
+    for a in dump_addrs_of(row.index)? {
+        push_addr(&mut row, &a);
+    }
+    Ok(row)
+}
+
 #[cfg(feature = "gateway")]
 #[derive(Debug, Clone)]
 pub struct GwRow {
@@ -286,31 +773,68 @@ pub struct GwRow {
     pub gw_v4: Vec<Ipv4Addr>,
     pub gw_v6: Vec<Ipv6Addr>,
     pub mac: Option<[u8; 6]>,
+    pub entries: Vec<GwEntry>,
+}
+
+#[cfg(feature = "gateway")]
+#[derive(Debug, Clone)]
+pub struct GwEntry {
+    pub ip: IpAddr,
+    pub metric: u32,
+    pub table: u32,
+    pub mac: Option<[u8; 6]>,
 }
 
+/// Collects the default route gateways keyed by output interface, only those of `if_index`
+/// when it is set.
 #[cfg(feature = "gateway")]
-pub fn collect_routes() -> io::Result<HashMap<u32, GwRow>> {
-    let routes = dump_routes()?;
-    let neighs = dump_neigh().unwrap_or_default();
+pub fn collect_routes(if_index: Option<u32>) -> io::Result<HashMap<u32, GwRow>> {
+    let routes: Vec<RouteRow> = dump_routes_of(if_index)?
+        .iter()
+        .filter_map(route_extract)
+        .collect();
+    let neighs = dump_neigh_of(if_index).unwrap_or_default();
 
     let mut m: HashMap<u32, GwRow> = HashMap::new();
     for rt in routes {
-        let (_dst, pfx, gw, oif) = route_extract(&rt);
         // default route only
-        if pfx != Some(0) {
+        if rt.prefix != 0 {
             continue;
         }
-        let oif = match oif {
-            Some(i) => i,
-            None => continue,
+        // Multipath routes carry one gateway per next hop, possibly on different interfaces
+        let paths: Vec<(Option<u32>, Option<IpAddr>)> = if rt.nexthops.is_empty() {
+            vec![(rt.oif, rt.gateway)]
+        } else {
+            rt.nexthops
+                .iter()
+                .map(|nh| (Some(nh.oif), nh.gateway))
+                .collect()
         };
-        if let Some(gwip) = gw {
+        // IPv4 routes omit RTA_PRIORITY when the metric is zero
+        let metric = rt.priority.unwrap_or(0);
+        for (oif, gw) in paths {
+            let (Some(oif), Some(gwip)) = (oif, gw) else {
+                continue;
+            };
             let e = m.entry(oif).or_insert(GwRow {
                 ifindex: oif,
                 gw_v4: vec![],
                 gw_v6: vec![],
                 mac: None,
+                entries: vec![],
             });
+            if !e
+                .entries
+                .iter()
+                .any(|x| x.ip == gwip && x.table == rt.table && x.metric == metric)
+            {
+                e.entries.push(GwEntry {
+                    ip: gwip,
+                    metric,
+                    table: rt.table,
+                    mac: None,
+                });
+            }
             match gwip {
                 IpAddr::V4(v4) => {
                     if !e.gw_v4.contains(&v4) {
@@ -328,14 +852,18 @@ pub fn collect_routes() -> io::Result<HashMap<u32, GwRow>> {
 
     let mut mac_candidates = super::gateway::GatewayMacCandidates::default();
     for n in neighs {
-        let (ip, mac, ifi) = neigh_extract(&n);
-        let ifi = match ifi {
-            Some(i) => i,
-            None => continue,
-        };
+        let NeighRow {
+            ip,
+            mac,
+            ifindex: ifi,
+            ..
+        } = neigh_extract(&n);
         let (Some(row), Some(m6), Some(ip)) = (m.get_mut(&ifi), mac, ip) else {
             continue;
         };
+        for entry in row.entries.iter_mut().filter(|x| x.ip == ip) {
+            entry.mac.get_or_insert(m6);
+        }
         match ip {
             IpAddr::V4(v4) if row.gw_v4.contains(&v4) => {
                 mac_candidates.record_ipv4(ifi, m6);
@@ -347,10 +875,130 @@ pub fn collect_routes() -> io::Result<HashMap<u32, GwRow>> {
         }
     }
 
+    // Unfiltered dumps of older kernels also describe other interfaces
+    if let Some(if_index) = if_index {
+        m.retain(|ifindex, _| *ifindex == if_index);
+    }
     for (ifindex, row) in &mut m {
         // Preserve the established IPv4 result when both families use different routers.
         row.mac = mac_candidates.get(*ifindex);
+        row.entries
+            .sort_by_key(|x| (x.metric, x.ip.is_ipv6(), x.table));
     }
 
     Ok(m)
 }
+
+#[cfg(test)]
+mod tests {
+    #![cfg(feature = "gateway")]
+    use super::{neigh_dump_request, route_dump_request, route_extract};
+    use netlink_packet_route::AddressFamily;
+    use netlink_packet_route::neighbour::NeighbourAttribute;
+    use netlink_packet_route::route::{
+        RouteAddress, RouteAttribute, RouteMessage, RouteMetric, RouteNextHop, RouteNextHopFlags,
+        RouteVia,
+    };
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn extracts_default_route_without_destination() {
+        let mut rt = RouteMessage::default();
+        rt.header.address_family = AddressFamily::Inet6;
+        rt.header.table = 254;
+        rt.attributes = vec![
+            RouteAttribute::Gateway(RouteAddress::Inet6("fe80::1".parse().unwrap())),
+            RouteAttribute::Oif(2),
+            RouteAttribute::Priority(1024),
+        ];
+
+        let row = route_extract(&rt).unwrap();
+
+        assert_eq!(row.dst, IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+        assert_eq!(row.prefix, 0);
+        assert_eq!(row.gateway, Some("fe80::1".parse().unwrap()));
+        assert_eq!(row.oif, Some(2));
+        assert_eq!(row.priority, Some(1024));
+        assert_eq!(row.table, 254);
+    }
+
+    #[test]
+    fn prefers_table_attribute_and_reads_mtu_metric() {
+        let mut rt = RouteMessage::default();
+        rt.header.address_family = AddressFamily::Inet;
+        rt.header.destination_prefix_length = 24;
+        rt.header.table = 252;
+        rt.attributes = vec![
+            RouteAttribute::Destination(RouteAddress::Inet(Ipv4Addr::new(10, 1, 2, 0))),
+            RouteAttribute::Table(1000),
+            RouteAttribute::PrefSource(RouteAddress::Inet(Ipv4Addr::new(10, 1, 2, 3))),
+            RouteAttribute::Metrics(vec![RouteMetric::Mtu(1400)]),
+        ];
+
+        let row = route_extract(&rt).unwrap();
+
+        assert_eq!(row.dst, IpAddr::V4(Ipv4Addr::new(10, 1, 2, 0)));
+        assert_eq!(row.prefix, 24);
+        assert_eq!(row.table, 1000);
+        assert_eq!(row.pref_src, Some(IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3))));
+        assert_eq!(row.mtu, Some(1400));
+    }
+
+    #[test]
+    fn skips_non_ip_families() {
+        let mut rt = RouteMessage::default();
+        rt.header.address_family = AddressFamily::Mpls;
+        assert!(route_extract(&rt).is_none());
+    }
+
+    #[test]
+    fn extracts_multipath_nexthops() {
+        let mut first = RouteNextHop::default();
+        first.interface_index = 2;
+        first.attributes = vec![RouteAttribute::Gateway(RouteAddress::Inet(Ipv4Addr::new(
+            192, 0, 2, 1,
+        )))];
+        let mut second = RouteNextHop::default();
+        second.interface_index = 3;
+        second.hops = 2;
+        second.flags = RouteNextHopFlags::Linkdown;
+        second.attributes = vec![RouteAttribute::Via(RouteVia::Inet6(
+            "fe80::1".parse().unwrap(),
+        ))];
+
+        let mut rt = RouteMessage::default();
+        rt.header.address_family = AddressFamily::Inet;
+        rt.header.table = 254;
+        rt.attributes = vec![RouteAttribute::MultiPath(vec![first, second])];
+
+        let row = route_extract(&rt).unwrap();
+
+        assert_eq!(row.gateway, None);
+        assert_eq!(row.oif, None);
+        assert_eq!(row.nexthops.len(), 2);
+        assert_eq!(row.nexthops[0].oif, 2);
+        assert_eq!(row.nexthops[0].weight, 1);
+        assert_eq!(
+            row.nexthops[0].gateway,
+            Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+        );
+        assert_eq!(row.nexthops[1].oif, 3);
+        assert_eq!(row.nexthops[1].weight, 3);
+        assert_eq!(row.nexthops[1].flags, 0x10);
+        assert_eq!(row.nexthops[1].gateway, Some("fe80::1".parse().unwrap()));
+    }
+
+    #[test]
+    fn filters_dumps_by_interface() {
+        assert!(route_dump_request(None).attributes.is_empty());
+        let routes = route_dump_request(Some(7));
+        assert_eq!(routes.attributes, vec![RouteAttribute::Oif(7)]);
+        // Strict checking rejects requests with header fields other than the filters
+        assert_eq!(routes.header, RouteMessage::default().header);
+
+        assert!(neigh_dump_request(None).attributes.is_empty());
+        let neighs = neigh_dump_request(Some(7));
+        assert_eq!(neighs.attributes, vec![NeighbourAttribute::IfIndex(7)]);
+        assert_eq!(neighs.header.ifindex, 0);
+    }
+}
@@ -1,6 +1,4 @@
-use netlink_packet_core::{
-    NLM_F_DUMP, NLM_F_DUMP_INTR, NLM_F_REQUEST, NetlinkMessage, NetlinkPayload,
-};
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_DUMP_INTR, NLM_F_REQUEST, NetlinkMessage};
 use netlink_packet_route::RouteNetlinkMessage;
 use netlink_sys::{Socket, SocketAddr};
 use std::{
@@ -8,10 +6,22 @@ use std::{
     time::{Duration, Instant},
 };
 
-const RECV_BUFSZ: usize = 1 << 20;
+pub(crate) const RECV_BUFSZ: usize = 1 << 20;
 const RECV_TIMEOUT: Duration = Duration::from_secs(2);
 const NLMSG_ALIGNTO: usize = 4;
 const MIN_NLMSG_HEADER_LEN: usize = 16;
+const NLMSG_NOOP: u16 = 1;
+const NLMSG_ERROR: u16 = 2;
+const NLMSG_DONE: u16 = 3;
+const NLMSG_OVERRUN: u16 = 4;
+
+/// A netlink message whose payload `netlink-packet-route` cannot decode.
+#[derive(Debug)]
+#[cfg_attr(target_os = "android", allow(dead_code))]
+pub(crate) struct RawMessage {
+    pub message_type: u16,
+    pub payload: Vec<u8>,
+}
 
 #[derive(Debug)]
 enum DatagramStatus {
@@ -31,8 +41,26 @@ pub(crate) fn set_non_blocking(sock: &Socket) -> io::Result<()> {
 }
 
 pub(crate) fn send_dump(sock: &mut Socket, msg: RouteNetlinkMessage, seq: u32) -> io::Result<()> {
+    send_with_flags(sock, msg, seq, NLM_F_REQUEST | NLM_F_DUMP)
+}
+
+#[cfg_attr(target_os = "android", allow(dead_code))]
+pub(crate) fn send_request(
+    sock: &mut Socket,
+    msg: RouteNetlinkMessage,
+    seq: u32,
+) -> io::Result<()> {
+    send_with_flags(sock, msg, seq, NLM_F_REQUEST)
+}
+
+fn send_with_flags(
+    sock: &mut Socket,
+    msg: RouteNetlinkMessage,
+    seq: u32,
+    flags: u16,
+) -> io::Result<()> {
     let mut nl = NetlinkMessage::from(msg);
-    nl.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+    nl.header.flags = flags;
     nl.header.sequence_number = seq;
     nl.header.port_number = 0;
     nl.finalize();
@@ -47,10 +75,33 @@ pub(crate) fn send_dump(sock: &mut Socket, msg: RouteNetlinkMessage, seq: u32) -
 
     let mut buf = vec![0; blen];
     nl.serialize(&mut buf);
+    send_bytes(sock, &buf)
+}
 
+/// Sends a request whose payload `netlink-packet-route` cannot encode.
+#[cfg_attr(target_os = "android", allow(dead_code))]
+pub(crate) fn send_raw(
+    sock: &mut Socket,
+    message_type: u16,
+    flags: u16,
+    seq: u32,
+    payload: &[u8],
+) -> io::Result<()> {
+    let len = MIN_NLMSG_HEADER_LEN + payload.len();
+    let mut buf = Vec::with_capacity(len);
+    buf.extend_from_slice(&(len as u32).to_ne_bytes());
+    buf.extend_from_slice(&message_type.to_ne_bytes());
+    buf.extend_from_slice(&flags.to_ne_bytes());
+    buf.extend_from_slice(&seq.to_ne_bytes());
+    buf.extend_from_slice(&0u32.to_ne_bytes());
+    buf.extend_from_slice(payload);
+    send_bytes(sock, &buf)
+}
+
+fn send_bytes(sock: &mut Socket, buf: &[u8]) -> io::Result<()> {
     let kernel = SocketAddr::new(0, 0);
     let sent = sock
-        .send_to(&buf, &kernel, 0)
+        .send_to(buf, &kernel, 0)
         .map_err(|e| io::Error::other(format!("netlink send: {e}")))?;
     if sent != buf.len() {
         return Err(io::Error::new(
@@ -69,9 +120,34 @@ fn parse_datagram(
     expect_seq: u32,
     out: &mut Vec<NetlinkMessage<RouteNetlinkMessage>>,
 ) -> io::Result<DatagramStatus> {
-    let mut offset = 0usize;
+    parse_messages(bytes, Some(expect_seq), out)
+}
 
-    while offset < bytes.len() {
+/// Header fields of a netlink message.
+#[derive(Debug)]
+struct MessageHeader {
+    message_type: u16,
+    flags: u16,
+    seq: u32,
+}
+
+/// Iterates over the messages of a datagram, yielding each header with the whole message,
+/// header included.
+///
+/// Fails on truncated headers, messages, and padding, after which the iteration ends.
+struct Messages<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Messages<'a> {
+    fn new(bytes: &'a [u8]) -> Messages<'a> {
+        Messages { bytes, offset: 0 }
+    }
+
+    fn read(&mut self) -> io::Result<(MessageHeader, &'a [u8])> {
+        let bytes = self.bytes;
+        let offset = self.offset;
         let remaining = bytes.len() - offset;
         if remaining < MIN_NLMSG_HEADER_LEN {
             return Err(io::Error::new(
@@ -80,18 +156,19 @@ fn parse_datagram(
             ));
         }
 
-        let consumed = u32::from_ne_bytes([
-            bytes[offset],
-            bytes[offset + 1],
-            bytes[offset + 2],
-            bytes[offset + 3],
-        ]) as usize;
+        let h = &bytes[offset..offset + MIN_NLMSG_HEADER_LEN];
+        let consumed = u32::from_ne_bytes([h[0], h[1], h[2], h[3]]) as usize;
         if consumed < MIN_NLMSG_HEADER_LEN {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidData,
                 format!("invalid netlink message length: length={consumed}"),
             ));
         }
+        let header = MessageHeader {
+            message_type: u16::from_ne_bytes([h[4], h[5]]),
+            flags: u16::from_ne_bytes([h[6], h[7]]),
+            seq: u32::from_ne_bytes([h[8], h[9], h[10], h[11]]),
+        };
 
         let message_end = offset
             .checked_add(consumed)
@@ -106,41 +183,6 @@ fn parse_datagram(
                 )
             })?;
 
-        let msg = NetlinkMessage::<RouteNetlinkMessage>::deserialize(&bytes[offset..message_end])
-            .map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("netlink deserialize: {e:?}"),
-            )
-        })?;
-
-        if msg.header.sequence_number == expect_seq {
-            match &msg.payload {
-                NetlinkPayload::Done(done) => {
-                    if msg.header.flags & NLM_F_DUMP_INTR != 0 {
-                        return Err(io::Error::other("netlink dump was interrupted"));
-                    }
-                    if done.code != 0 {
-                        return Err(io::Error::other(format!(
-                            "netlink dump failed: code={}",
-                            done.code
-                        )));
-                    }
-                    return Ok(DatagramStatus::Done);
-                }
-                NetlinkPayload::Error(error) => {
-                    if let Some(code) = error.code {
-                        return Err(io::Error::other(format!("netlink error: code={code}")));
-                    }
-                }
-                NetlinkPayload::Overrun(_) => {
-                    return Err(io::Error::other("netlink receive overrun"));
-                }
-                NetlinkPayload::Noop => {}
-                _ => out.push(msg),
-            }
-        }
-
         let aligned = nlmsg_align(consumed).ok_or_else(|| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
@@ -156,7 +198,7 @@ fn parse_datagram(
 
         if next_offset > bytes.len() {
             if message_end == bytes.len() {
-                offset = bytes.len();
+                self.offset = bytes.len();
             } else {
                 return Err(io::Error::new(
                     io::ErrorKind::InvalidData,
@@ -164,17 +206,188 @@ fn parse_datagram(
                 ));
             }
         } else {
-            offset = next_offset;
+            self.offset = next_offset;
+        }
+        Ok((header, &bytes[offset..message_end]))
+    }
+}
+
+impl<'a> Iterator for Messages<'a> {
+    type Item = io::Result<(MessageHeader, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.bytes.len() {
+            return None;
+        }
+        let item = self.read();
+        if item.is_err() {
+            self.offset = self.bytes.len();
+        }
+        Some(item)
+    }
+}
+
+/// Splits a datagram into messages decoded by `decode`, keeping only replies to `expect_seq`
+/// when it is set.
+///
+/// Control messages are handled here: `NLMSG_DONE` ends the reply, and `NLMSG_ERROR` with a
+/// non-zero code, `NLMSG_OVERRUN`, and interrupted dumps fail it.
+fn split_datagram<T>(
+    bytes: &[u8],
+    expect_seq: Option<u32>,
+    out: &mut Vec<T>,
+    decode: impl Fn(&MessageHeader, &[u8]) -> io::Result<T>,
+) -> io::Result<DatagramStatus> {
+    for message in Messages::new(bytes) {
+        let (header, message) = message?;
+        if expect_seq.is_some_and(|seq| header.seq != seq) {
+            continue;
+        }
+        let payload = &message[MIN_NLMSG_HEADER_LEN..];
+        let code = || {
+            payload
+                .get(..4)
+                .map(|c| i32::from_ne_bytes([c[0], c[1], c[2], c[3]]))
+                .unwrap_or(0)
+        };
+        match header.message_type {
+            NLMSG_DONE => {
+                if header.flags & NLM_F_DUMP_INTR != 0 {
+                    return Err(io::Error::other("netlink dump was interrupted"));
+                }
+                if code() != 0 {
+                    return Err(io::Error::other(format!(
+                        "netlink dump failed: code={}",
+                        code()
+                    )));
+                }
+                return Ok(DatagramStatus::Done);
+            }
+            NLMSG_ERROR => {
+                if code() != 0 {
+                    return Err(io::Error::from_raw_os_error(-code()));
+                }
+            }
+            NLMSG_OVERRUN => {
+                return Err(io::Error::other("netlink receive overrun"));
+            }
+            NLMSG_NOOP => {}
+            _ => out.push(decode(&header, message)?),
         }
     }
 
     Ok(DatagramStatus::Continue)
 }
 
+/// Splits a datagram into messages, keeping only replies to `expect_seq` when it is set.
+///
+/// Notifications carry the sequence number of whichever request caused them, so multicast
+/// receivers pass `None`.
+fn parse_messages(
+    bytes: &[u8],
+    expect_seq: Option<u32>,
+    out: &mut Vec<NetlinkMessage<RouteNetlinkMessage>>,
+) -> io::Result<DatagramStatus> {
+    split_datagram(bytes, expect_seq, out, |_, message| {
+        NetlinkMessage::<RouteNetlinkMessage>::deserialize(message).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("netlink deserialize: {e:?}"),
+            )
+        })
+    })
+}
+
+/// Splits a datagram into undecoded messages, keeping only replies to `expect_seq`.
+fn parse_raw_datagram(
+    bytes: &[u8],
+    expect_seq: u32,
+    out: &mut Vec<RawMessage>,
+) -> io::Result<DatagramStatus> {
+    split_datagram(bytes, Some(expect_seq), out, |header, message| {
+        Ok(RawMessage {
+            message_type: header.message_type,
+            payload: message[MIN_NLMSG_HEADER_LEN..].to_vec(),
+        })
+    })
+}
+
 pub(crate) fn recv_multi(
     sock: &mut Socket,
     expect_seq: u32,
 ) -> io::Result<Vec<NetlinkMessage<RouteNetlinkMessage>>> {
+    recv_datagrams(sock, expect_seq, false, parse_datagram)
+}
+
+/// Receives the undecoded replies to a dump request.
+#[cfg_attr(target_os = "android", allow(dead_code))]
+pub(crate) fn recv_raw_multi(sock: &mut Socket, expect_seq: u32) -> io::Result<Vec<RawMessage>> {
+    recv_datagrams(sock, expect_seq, false, parse_raw_datagram)
+}
+
+/// Receives the undecoded reply to a non-dump request.
+#[cfg_attr(target_os = "android", allow(dead_code))]
+pub(crate) fn recv_raw_reply(sock: &mut Socket, expect_seq: u32) -> io::Result<RawMessage> {
+    recv_datagrams(sock, expect_seq, true, parse_raw_datagram)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| io::Error::other("netlink request completed without a reply"))
+}
+
+/// Receives the reply to a non-dump request, which is a single message without `NLMSG_DONE`.
+#[cfg_attr(target_os = "android", allow(dead_code))]
+pub(crate) fn recv_reply(
+    sock: &mut Socket,
+    expect_seq: u32,
+) -> io::Result<NetlinkMessage<RouteNetlinkMessage>> {
+    recv_datagrams(sock, expect_seq, true, parse_datagram)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| io::Error::other("netlink request completed without a reply"))
+}
+
+/// Blocks until the kernel delivers at least one multicast notification.
+///
+/// Fails with `ENOBUFS` when the socket receive buffer overflowed and notifications were lost.
+#[cfg(feature = "gateway")]
+#[cfg_attr(target_os = "android", allow(dead_code))]
+pub(crate) fn recv_notifications(
+    sock: &mut Socket,
+    buf: &mut [u8],
+) -> io::Result<Vec<NetlinkMessage<RouteNetlinkMessage>>> {
+    loop {
+        match sock.recv_from(&mut &mut buf[..], libc::MSG_TRUNC) {
+            Ok((size, from)) => {
+                if from.port_number() != 0 {
+                    continue;
+                }
+                if size > buf.len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "netlink datagram exceeds receive buffer: size={size}, capacity={}",
+                            buf.len()
+                        ),
+                    ));
+                }
+                let mut out = Vec::new();
+                parse_messages(&buf[..size], None, &mut out)?;
+                if !out.is_empty() {
+                    return Ok(out);
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn recv_datagrams<T>(
+    sock: &mut Socket,
+    expect_seq: u32,
+    single: bool,
+    parse: fn(&[u8], u32, &mut Vec<T>) -> io::Result<DatagramStatus>,
+) -> io::Result<Vec<T>> {
     let mut out = Vec::new();
     let mut buf = vec![0u8; RECV_BUFSZ];
     let deadline = Instant::now() + RECV_TIMEOUT;
@@ -202,9 +415,10 @@ pub(crate) fn recv_multi(
                     ));
                 }
                 if matches!(
-                    parse_datagram(&buf[..size], expect_seq, &mut out)?,
+                    parse(&buf[..size], expect_seq, &mut out)?,
                     DatagramStatus::Done
-                ) {
+                ) || (single && !out.is_empty())
+                {
                     return Ok(out);
                 }
             }
@@ -219,7 +433,7 @@ pub(crate) fn recv_multi(
 
 #[cfg(test)]
 mod tests {
-    use super::{DatagramStatus, MIN_NLMSG_HEADER_LEN, parse_datagram};
+    use super::{DatagramStatus, MIN_NLMSG_HEADER_LEN, parse_datagram, parse_raw_datagram};
     use netlink_packet_core::{DoneMessage, NLM_F_DUMP_INTR, NetlinkMessage, NetlinkPayload};
     use netlink_packet_route::{RouteNetlinkMessage, link::LinkMessage};
 
@@ -314,4 +528,23 @@ mod tests {
 
         assert_eq!(error.to_string(), "netlink receive overrun");
     }
+
+    #[test]
+    fn raw_parser_shares_control_handling() {
+        let link = serialize(link_message(SEQ));
+        let mut bytes = link.clone();
+        bytes.extend(serialize(done_message()));
+        let mut messages = Vec::new();
+
+        let status = parse_raw_datagram(&bytes, SEQ, &mut messages).unwrap();
+
+        assert!(matches!(status, DatagramStatus::Done));
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].payload, link[MIN_NLMSG_HEADER_LEN..]);
+
+        let mut done = done_message();
+        done.header.flags = NLM_F_DUMP_INTR;
+        let error = parse_raw_datagram(&serialize(done), SEQ, &mut messages).unwrap_err();
+        assert_eq!(error.to_string(), "netlink dump was interrupted");
+    }
 }
@@ -0,0 +1,213 @@
+use super::netlink::{self, LinkSelector, RouteRow};
+use crate::route::lookup::RouteQuery;
+use crate::route::rule::RoutingRule;
+use crate::route::table::{NextHop, Route};
+use crate::route::types::IpFamily;
+use ipnet::IpNet;
+use netlink_packet_route::AddressFamily;
+use netlink_packet_route::rule::{RuleAttribute, RuleFlags, RuleMessage};
+use std::collections::HashMap;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+const RTN_UNICAST: u8 = 1;
+
+// Addresses of a.root-servers.net, used as representative Internet destinations
+// when resolving the default route. Lookups never send traffic.
+const DEFAULT_PROBE_V4: Ipv4Addr = Ipv4Addr::new(198, 41, 0, 4);
+const DEFAULT_PROBE_V6: Ipv6Addr = Ipv6Addr::new(0x2001, 0x503, 0xba3e, 0, 0, 0, 0x2, 0x30);
+
+pub(crate) fn to_route(r: RouteRow, names: &HashMap<u32, String>) -> Option<Route> {
+    let destination = IpNet::new(r.dst, r.prefix).ok()?;
+    Some(Route {
+        destination,
+        gateway: r.gateway,
+        if_index: r.oif,
+        if_name: r.oif.and_then(|i| names.get(&i).cloned()),
+        metric: r.priority,
+        table: r.table,
+        protocol: r.protocol.into(),
+        scope: r.scope.into(),
+        route_type: r.kind.into(),
+        pref_source: r.pref_src,
+        mtu: r.mtu,
+        nexthops: r
+            .nexthops
+            .into_iter()
+            .map(|nh| NextHop {
+                gateway: nh.gateway,
+                if_index: nh.oif,
+                if_name: names.get(&nh.oif).cloned(),
+                weight: nh.weight,
+                flags: nh.flags,
+            })
+            .collect(),
+    })
+}
+
+pub fn routes() -> io::Result<Vec<Route>> {
+    let rows = netlink::collect_route_rows()?;
+    let names = netlink::collect_link_names().unwrap_or_default();
+    Ok(rows
+        .into_iter()
+        .filter_map(|r| to_route(r, &names))
+        .collect())
+}
+
+pub fn lookup(query: &RouteQuery) -> io::Result<Route> {
+    let row = netlink::get_route(query)?;
+    // A lookup names at most a few interfaces, so request them one by one instead of
+    // dumping every link.
+    let mut indices: Vec<u32> = row
+        .oif
+        .into_iter()
+        .chain(row.nexthops.iter().map(|nh| nh.oif))
+        .collect();
+    indices.sort_unstable();
+    indices.dedup();
+    let names = indices
+        .into_iter()
+        .filter_map(|index| Some((index, link_name(index)?)))
+        .collect();
+    to_route(row, &names)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid route prefix"))
+}
+
+fn link_name(index: u32) -> Option<String> {
+    let link = netlink::get_link(LinkSelector::Index(index)).ok()??;
+    netlink::name_from_link(&link)
+}
+
+/// Output interface and gateway of the route selected for Internet-bound traffic.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct DefaultRoute {
+    pub if_index: u32,
+    pub gateway: Option<IpAddr>,
+}
+
+/// Resolves the route the kernel would use for Internet-bound traffic of one family.
+///
+/// Returns `Ok(None)` when the kernel answered that the family has no usable route, and an
+/// error only when the lookup itself failed.
+pub fn default_route_for(family: IpFamily) -> io::Result<Option<DefaultRoute>> {
+    let dst = match family {
+        IpFamily::V4 => IpAddr::V4(DEFAULT_PROBE_V4),
+        IpFamily::V6 => IpAddr::V6(DEFAULT_PROBE_V6),
+    };
+    match netlink::get_route(&RouteQuery::new(dst)) {
+        Ok(row) if row.kind == RTN_UNICAST => Ok(row.oif.map(|if_index| DefaultRoute {
+            if_index,
+            gateway: row.gateway,
+        })),
+        // IPv6 reports blackhole and prohibit routes through the loopback device
+        Ok(_) => Ok(None),
+        Err(e) if is_no_route(&e) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns `true` for the errors the kernel uses to report that a destination has no route,
+/// or only an unreachable, prohibit, or blackhole route.
+fn is_no_route(e: &io::Error) -> bool {
+    matches!(
+        e.raw_os_error(),
+        Some(libc::ENETUNREACH | libc::EHOSTUNREACH | libc::EACCES)
+    )
+}
+
+fn to_rule(msg: &RuleMessage) -> Option<RoutingRule> {
+    let family = match msg.header.family {
+        AddressFamily::Inet => IpFamily::V4,
+        AddressFamily::Inet6 => IpFamily::V6,
+        _ => return None,
+    };
+    let mut rule = RoutingRule {
+        family,
+        // Rules without FRA_PRIORITY are the implicit priority 0 rule
+        priority: 0,
+        source: None,
+        destination: None,
+        invert: msg.header.flags.contains(RuleFlags::Invert),
+        iif: None,
+        oif: None,
+        fwmark: None,
+        fwmask: None,
+        uid_range: None,
+        l3mdev: false,
+        action: u8::from(msg.header.action).into(),
+        table: match msg.header.table {
+            0 => None,
+            t => Some(t as u32),
+        },
+        goto: None,
+    };
+
+    for nla in &msg.attributes {
+        match nla {
+            RuleAttribute::Priority(p) => rule.priority = *p,
+            RuleAttribute::Source(ip) => {
+                rule.source = IpNet::new(*ip, msg.header.src_len).ok();
+            }
+            RuleAttribute::Destination(ip) => {
+                rule.destination = IpNet::new(*ip, msg.header.dst_len).ok();
+            }
+            RuleAttribute::Iifname(n) => rule.iif = Some(n.clone()),
+            RuleAttribute::Oifname(n) => rule.oif = Some(n.clone()),
+            RuleAttribute::FwMark(m) => rule.fwmark = Some(*m),
+            RuleAttribute::FwMask(m) => rule.fwmask = Some(*m),
+            RuleAttribute::UidRange(r) => rule.uid_range = Some(r.start..=r.end),
+            RuleAttribute::L3MDev(v) => rule.l3mdev = *v,
+            // FRA_TABLE carries ids that do not fit into the header field
+            RuleAttribute::Table(t) if *t != 0 => rule.table = Some(*t),
+            RuleAttribute::Goto(g) => rule.goto = Some(*g),
+            _ => {}
+        }
+    }
+    Some(rule)
+}
+
+pub fn rules() -> io::Result<Vec<RoutingRule>> {
+    let mut rules: Vec<RoutingRule> = netlink::dump_rules()?.iter().filter_map(to_rule).collect();
+    rules.sort_by_key(|r| (r.family == IpFamily::V6, r.priority));
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_rule;
+    use crate::route::rule::RuleAction;
+    use crate::route::types::IpFamily;
+    use netlink_packet_route::AddressFamily;
+    use netlink_packet_route::rule::{RuleAction as NlRuleAction, RuleAttribute, RuleMessage};
+
+    #[test]
+    fn converts_fwmark_rule_with_large_table() {
+        let mut msg = RuleMessage::default();
+        msg.header.family = AddressFamily::Inet6;
+        msg.header.action = NlRuleAction::ToTable;
+        msg.header.table = 252;
+        msg.header.src_len = 48;
+        msg.attributes = vec![
+            RuleAttribute::Priority(32765),
+            RuleAttribute::Source("2001:db8:1::".parse().unwrap()),
+            RuleAttribute::FwMark(0xca6c),
+            RuleAttribute::Table(51820),
+        ];
+
+        let rule = to_rule(&msg).unwrap();
+
+        assert_eq!(rule.family, IpFamily::V6);
+        assert_eq!(rule.priority, 32765);
+        assert_eq!(rule.source, Some("2001:db8:1::/48".parse().unwrap()));
+        assert_eq!(rule.destination, None);
+        assert_eq!(rule.fwmark, Some(0xca6c));
+        assert_eq!(rule.action, RuleAction::ToTable);
+        assert_eq!(rule.table, Some(51820));
+    }
+
+    #[test]
+    fn skips_non_ip_families() {
+        let msg = RuleMessage::default();
+        assert!(to_rule(&msg).is_none());
+    }
+}
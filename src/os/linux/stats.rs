@@ -0,0 +1,279 @@
+use crate::stats::counters::{CounterWidth, ExtendedStats, InterfaceStats};
+use netlink_packet_route::link::{LinkAttribute, LinkMessage, Stats, Stats64};
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+#[cfg(not(target_os = "android"))]
+use std::ffi::CString;
+#[cfg(not(target_os = "android"))]
+use std::fs::read_to_string;
+#[cfg(not(target_os = "android"))]
+use std::io;
+
+#[cfg(not(target_os = "android"))]
+const PATH_PROC_NET_DEV: &str = "/proc/net/dev";
+
+fn extended_from_stats64(s: &Stats64) -> ExtendedStats {
+    ExtendedStats {
+        rx_packets: s.rx_packets,
+        tx_packets: s.tx_packets,
+        rx_errors: s.rx_errors,
+        tx_errors: s.tx_errors,
+        rx_dropped: s.rx_dropped,
+        tx_dropped: s.tx_dropped,
+        multicast: s.multicast,
+        collisions: s.collisions,
+        rx_length_errors: s.rx_length_errors,
+        rx_over_errors: s.rx_over_errors,
+        rx_crc_errors: s.rx_crc_errors,
+        rx_frame_errors: s.rx_frame_errors,
+        rx_fifo_errors: s.rx_fifo_errors,
+        rx_missed_errors: s.rx_missed_errors,
+        tx_aborted_errors: s.tx_aborted_errors,
+        tx_carrier_errors: s.tx_carrier_errors,
+        tx_fifo_errors: s.tx_fifo_errors,
+        tx_heartbeat_errors: s.tx_heartbeat_errors,
+        tx_window_errors: s.tx_window_errors,
+        rx_compressed: s.rx_compressed,
+        tx_compressed: s.tx_compressed,
+        rx_nohandler: s.rx_nohandler,
+    }
+}
+
+fn extended_from_stats(s: &Stats) -> ExtendedStats {
+    ExtendedStats {
+        rx_packets: s.rx_packets.into(),
+        tx_packets: s.tx_packets.into(),
+        rx_errors: s.rx_errors.into(),
+        tx_errors: s.tx_errors.into(),
+        rx_dropped: s.rx_dropped.into(),
+        tx_dropped: s.tx_dropped.into(),
+        multicast: s.multicast.into(),
+        collisions: s.collisions.into(),
+        rx_length_errors: s.rx_length_errors.into(),
+        rx_over_errors: s.rx_over_errors.into(),
+        rx_crc_errors: s.rx_crc_errors.into(),
+        rx_frame_errors: s.rx_frame_errors.into(),
+        rx_fifo_errors: s.rx_fifo_errors.into(),
+        rx_missed_errors: s.rx_missed_errors.into(),
+        tx_aborted_errors: s.tx_aborted_errors.into(),
+        tx_carrier_errors: s.tx_carrier_errors.into(),
+        tx_fifo_errors: s.tx_fifo_errors.into(),
+        tx_heartbeat_errors: s.tx_heartbeat_errors.into(),
+        tx_window_errors: s.tx_window_errors.into(),
+        rx_compressed: s.rx_compressed.into(),
+        tx_compressed: s.tx_compressed.into(),
+        rx_nohandler: s.rx_nohandler.into(),
+    }
+}
+
+/// Reads the counters carried by an `RTM_NEWLINK` message.
+///
+/// `IFLA_STATS64` is preferred. The 32-bit `IFLA_STATS` attribute is used only on kernels that
+/// do not send the 64-bit one.
+pub fn stats_from_link(link: &LinkMessage) -> Option<InterfaceStats> {
+    let mut legacy = None;
+    for nla in &link.attributes {
+        match nla {
+            LinkAttribute::Stats64(s) => {
+                return Some(InterfaceStats {
+                    rx_bytes: s.rx_bytes,
+                    tx_bytes: s.tx_bytes,
+                    timestamp: Some(SystemTime::now()),
+                    counter_width: CounterWidth::Bits64,
+                    extended: Some(extended_from_stats64(s)),
+                });
+            }
+            LinkAttribute::Stats(s) => legacy = Some(s),
+            _ => {}
+        }
+    }
+    legacy.map(|s| InterfaceStats {
+        rx_bytes: s.rx_bytes.into(),
+        tx_bytes: s.tx_bytes.into(),
+        timestamp: Some(SystemTime::now()),
+        counter_width: CounterWidth::Bits32,
+        extended: Some(extended_from_stats(s)),
+    })
+}
+
+/// Collects the counters of every link in an `RTM_GETLINK` dump, keyed by index.
+pub fn stats_from_links(links: &[LinkMessage]) -> BTreeMap<u32, InterfaceStats> {
+    links
+        .iter()
+        .filter_map(|link| Some((link.header.index, stats_from_link(link)?)))
+        .collect()
+}
+
+/// Collects the counters of every interface with one netlink dump.
+///
+/// Falls back to a single read of `/proc/net/dev` when netlink is unavailable.
+#[cfg(not(target_os = "android"))]
+pub fn all_stats() -> io::Result<BTreeMap<u32, InterfaceStats>> {
+    let err = match super::netlink::dump_links() {
+        Ok(links) => return Ok(stats_from_links(&links)),
+        Err(e) => e,
+    };
+    let Ok(text) = read_to_string(PATH_PROC_NET_DEV) else {
+        return Err(err);
+    };
+    Ok(parse_proc_net_dev_all(&text)
+        .filter_map(|(name, stats)| {
+            let name = CString::new(name).ok()?;
+            let index = unsafe { libc::if_nametoindex(name.as_ptr()) };
+            (index != 0).then_some((index, stats))
+        })
+        .collect())
+}
+
+/// Requests the counters of one interface over netlink.
+#[cfg(not(target_os = "android"))]
+pub fn stats_by_index(index: u32) -> Option<InterfaceStats> {
+    let link = super::netlink::get_link(super::netlink::LinkSelector::Index(index)).ok()??;
+    stats_from_link(&link)
+}
+
+/// Requests the counters of the interface named `name` over netlink.
+#[cfg(not(target_os = "android"))]
+pub fn stats_by_name(name: &str) -> Option<InterfaceStats> {
+    let link = super::netlink::get_link(super::netlink::LinkSelector::Name(name)).ok()??;
+    stats_from_link(&link)
+}
+
+/// Reads the byte counters in `/sys/class/net/<name>/statistics`.
+///
+/// The other counters are left to netlink and `/proc/net/dev`, since sysfs needs one read
+/// per counter.
+#[cfg(not(target_os = "android"))]
+pub fn stats_from_sysfs(name: &str) -> Option<InterfaceStats> {
+    let dir = format!("/sys/class/net/{}/statistics", name);
+    let read = |counter: &str| -> Option<u64> {
+        read_to_string(format!("{}/{}", dir, counter))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    };
+
+    Some(InterfaceStats {
+        rx_bytes: read("rx_bytes")?,
+        tx_bytes: read("tx_bytes")?,
+        timestamp: Some(SystemTime::now()),
+        counter_width: CounterWidth::Bits64,
+        extended: None,
+    })
+}
+
+/// Reads the counters of `name` from `/proc/net/dev`.
+#[cfg(not(target_os = "android"))]
+pub fn stats_from_proc(name: &str) -> Option<InterfaceStats> {
+    let text = read_to_string(PATH_PROC_NET_DEV).ok()?;
+    parse_proc_net_dev(&text, name)
+}
+
+/// Parses one interface from the `/proc/net/dev` table.
+#[cfg(not(target_os = "android"))]
+fn parse_proc_net_dev(text: &str, name: &str) -> Option<InterfaceStats> {
+    parse_proc_net_dev_all(text).find_map(|(ifname, stats)| (ifname == name).then_some(stats))
+}
+
+/// Parses every interface of the `/proc/net/dev` table.
+///
+/// The table aggregates several counters: its receive `drop` column includes missed packets,
+/// and its transmit `carrier` column includes aborted, window, and heartbeat errors. These
+/// sums are reported in `rx_dropped` and `tx_carrier_errors`.
+#[cfg(not(target_os = "android"))]
+fn parse_proc_net_dev_all(text: &str) -> impl Iterator<Item = (&str, InterfaceStats)> {
+    let now = SystemTime::now();
+    // The first two lines are column headers.
+    text.lines().skip(2).filter_map(move |line| {
+        let (ifname, counters) = line.split_once(':')?;
+        let v: Vec<u64> = counters
+            .split_whitespace()
+            .map(|field| field.parse().ok())
+            .collect::<Option<_>>()?;
+        if v.len() < 16 {
+            return None;
+        }
+
+        let stats = InterfaceStats {
+            rx_bytes: v[0],
+            tx_bytes: v[8],
+            timestamp: Some(now),
+            counter_width: CounterWidth::Bits64,
+            extended: Some(ExtendedStats {
+                rx_packets: v[1],
+                rx_errors: v[2],
+                rx_dropped: v[3],
+                rx_fifo_errors: v[4],
+                rx_frame_errors: v[5],
+                rx_compressed: v[6],
+                multicast: v[7],
+                tx_packets: v[9],
+                tx_errors: v[10],
+                tx_dropped: v[11],
+                tx_fifo_errors: v[12],
+                collisions: v[13],
+                tx_carrier_errors: v[14],
+                tx_compressed: v[15],
+                ..ExtendedStats::default()
+            }),
+        };
+        Some((ifname.trim(), stats))
+    })
+}
+
+#[cfg(test)]
+#[cfg(not(target_os = "android"))]
+mod tests {
+    use super::{parse_proc_net_dev, parse_proc_net_dev_all};
+
+    const PROC_NET_DEV: &str = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo: 50570301    6671    0    0    0     0          0         0 50570301    6671    0    0    0     0       0          0
+  eth0: 4991135     464    3    7    0     1          0        12    46984     517    0    2    0     4       5          0
+";
+
+    #[test]
+    fn parses_proc_net_dev_counters() {
+        let stats = parse_proc_net_dev(PROC_NET_DEV, "eth0").unwrap();
+        let ext = stats.extended.unwrap();
+
+        assert_eq!((stats.rx_bytes, stats.tx_bytes), (4991135, 46984));
+        assert_eq!((ext.rx_packets, ext.tx_packets), (464, 517));
+        assert_eq!(
+            (ext.rx_errors, ext.rx_dropped, ext.rx_frame_errors),
+            (3, 7, 1)
+        );
+        assert_eq!(ext.multicast, 12);
+        assert_eq!(
+            (ext.tx_dropped, ext.collisions, ext.tx_carrier_errors),
+            (2, 4, 5)
+        );
+        assert_eq!(ext.rx_nohandler, 0);
+
+        assert!(parse_proc_net_dev(PROC_NET_DEV, "eth").is_none());
+        assert!(parse_proc_net_dev(PROC_NET_DEV, "wlan0").is_none());
+    }
+
+    #[test]
+    fn parses_every_proc_net_dev_row() {
+        let names: Vec<&str> = parse_proc_net_dev_all(PROC_NET_DEV)
+            .map(|(n, _)| n)
+            .collect();
+        assert_eq!(names, vec!["lo", "eth0"]);
+    }
+
+    #[test]
+    fn bulk_stats_match_interface_indexes() {
+        let Ok(all) = super::all_stats() else {
+            return;
+        };
+        for iface in crate::interface::get_interfaces() {
+            if iface.stats.is_some() {
+                assert!(all.contains_key(&iface.index), "{}", iface.name);
+            }
+        }
+    }
+}
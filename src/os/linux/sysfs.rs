@@ -85,6 +85,11 @@ pub fn get_interface_type(ifname: &str) -> InterfaceType {
         None => return InterfaceType::Unknown,
     };
 
+    interface_type_from_arphrd(ty)
+}
+
+/// Maps an `ARPHRD_*` link-layer type to an interface type without probing sysfs.
+pub fn interface_type_from_arphrd(ty: u32) -> InterfaceType {
     if ty == super::arp::ARPHRD_ETHER {
         InterfaceType::Ethernet
     } else {
@@ -113,3 +118,26 @@ pub fn operstate(ifname: &str) -> OperState {
         .and_then(|s| s.parse::<OperState>().ok())
         .unwrap_or(OperState::Unknown)
 }
+
+/// Get the names of the interfaces linked by the `<prefix><name>` adjacency symlinks.
+fn adjacent_interfaces(ifname: &str, prefix: &str) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(PathBuf::from("/sys/class/net").join(ifname)) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix(prefix).map(str::to_owned)
+        })
+        .collect()
+}
+
+/// Get the interfaces that the interface is stacked on (`lower_*`).
+pub fn lower_interfaces(ifname: &str) -> Vec<String> {
+    adjacent_interfaces(ifname, "lower_")
+}
+
+/// Get the interfaces stacked on the interface (`upper_*`).
+pub fn upper_interfaces(ifname: &str) -> Vec<String> {
+    adjacent_interfaces(ifname, "upper_")
+}
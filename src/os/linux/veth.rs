@@ -0,0 +1,117 @@
+use super::netlink::{self, LinkSelector};
+use crate::interface::kind::LinkKind;
+use crate::net::veth::Veth;
+use netlink_packet_route::link::{LinkAttribute, LinkMessage};
+use std::collections::HashMap;
+use std::io;
+
+pub fn veths() -> io::Result<Vec<Veth>> {
+    let links = netlink::dump_links()?;
+    let names: HashMap<u32, String> = links
+        .iter()
+        .filter_map(|link| Some((link.header.index, netlink::name_from_link(link)?)))
+        .collect();
+    let mut veths: Vec<Veth> = links.iter().filter_map(veth_from_link).collect();
+    for veth in &mut veths {
+        veth.peer_name = match veth.peer_netns_id {
+            None => names.get(&veth.peer_index).cloned(),
+            Some(netns_id) => remote_name(veth.peer_index, netns_id),
+        };
+    }
+    veths.sort_by_key(|v| v.index);
+    Ok(veths)
+}
+
+pub fn veth_by_index(index: u32) -> io::Result<Option<Veth>> {
+    let Some(link) = netlink::get_link(LinkSelector::Index(index))? else {
+        return Ok(None);
+    };
+    let Some(mut veth) = veth_from_link(&link) else {
+        return Ok(None);
+    };
+    veth.peer_name = match veth.peer_netns_id {
+        None => netlink::get_link(LinkSelector::Index(veth.peer_index))
+            .ok()
+            .flatten()
+            .and_then(|peer| netlink::name_from_link(&peer)),
+        Some(netns_id) => remote_name(veth.peer_index, netns_id),
+    };
+    Ok(Some(veth))
+}
+
+/// Looks up the name of a link in another network namespace. Returns `None` on kernels without
+/// `IFLA_TARGET_NETNSID` or without `CAP_NET_ADMIN` in the target namespace.
+fn remote_name(index: u32, netns_id: i32) -> Option<String> {
+    let link = netlink::get_link(LinkSelector::IndexInNetns(index, netns_id)).ok()??;
+    // Kernels that ignore the attribute answer with the link of the same index in the
+    // current namespace. Others echo the target namespace id.
+    if !link
+        .attributes
+        .iter()
+        .any(|nla| matches!(nla, LinkAttribute::IfNetnsId(_)))
+    {
+        return None;
+    }
+    netlink::name_from_link(&link)
+}
+
+/// Reads the peer from `IFLA_LINK` and `IFLA_LINK_NETNSID`. The peer name is left unresolved.
+fn veth_from_link(link: &LinkMessage) -> Option<Veth> {
+    if super::linkinfo::link_kind(link)? != LinkKind::Veth {
+        return None;
+    }
+    let mut peer_index = None;
+    let mut peer_netns_id = None;
+    for nla in &link.attributes {
+        match nla {
+            LinkAttribute::Link(index) => peer_index = Some(*index),
+            LinkAttribute::LinkNetNsId(id) => peer_netns_id = Some(*id),
+            _ => {}
+        }
+    }
+    Some(Veth {
+        index: link.header.index,
+        name: netlink::name_from_link(link).unwrap_or_default(),
+        // The peer index is 0 once the other end has been deleted.
+        peer_index: peer_index.filter(|i| *i != 0)?,
+        peer_netns_id,
+        peer_name: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::veth_from_link;
+    use netlink_packet_route::link::{InfoKind, LinkAttribute, LinkInfo, LinkMessage};
+
+    fn link(index: u32, attributes: Vec<LinkAttribute>) -> LinkMessage {
+        let mut msg = LinkMessage::default();
+        msg.header.index = index;
+        msg.attributes = attributes;
+        msg
+    }
+
+    #[test]
+    fn reads_peer_attributes() {
+        let kind = || LinkAttribute::LinkInfo(vec![LinkInfo::Kind(InfoKind::Veth)]);
+
+        let remote = link(
+            7,
+            vec![
+                LinkAttribute::IfName("veth1a2b".into()),
+                LinkAttribute::Link(2),
+                LinkAttribute::LinkNetNsId(0),
+                kind(),
+            ],
+        );
+        let veth = veth_from_link(&remote).unwrap();
+        assert_eq!((veth.index, veth.name.as_str()), (7, "veth1a2b"));
+        assert_eq!((veth.peer_index, veth.peer_netns_id), (2, Some(0)));
+        assert!(!veth.is_peer_local());
+
+        let local = link(8, vec![LinkAttribute::Link(9), kind()]);
+        assert!(veth_from_link(&local).unwrap().is_peer_local());
+
+        assert!(veth_from_link(&link(4, vec![LinkAttribute::Link(4)])).is_none());
+    }
+}
@@ -0,0 +1,288 @@
+use super::netlink::{self, LinkSelector};
+use crate::interface::kind::LinkKind;
+use crate::net::wireguard::{WireGuardDevice, WireGuardKey, WireGuardPeer};
+use ipnet::IpNet;
+use netlink_packet_core::{NlaBuffer, NlasIterator};
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::time::{Duration, SystemTime};
+
+// Attributes of WG_CMD_GET_DEVICE replies, from linux/wireguard.h.
+const WGDEVICE_A_IFINDEX: u16 = 1;
+const WGDEVICE_A_IFNAME: u16 = 2;
+const WGDEVICE_A_PUBLIC_KEY: u16 = 4;
+const WGDEVICE_A_LISTEN_PORT: u16 = 6;
+const WGDEVICE_A_FWMARK: u16 = 7;
+const WGDEVICE_A_PEERS: u16 = 8;
+const WGPEER_A_PUBLIC_KEY: u16 = 1;
+const WGPEER_A_ENDPOINT: u16 = 4;
+const WGPEER_A_PERSISTENT_KEEPALIVE_INTERVAL: u16 = 5;
+const WGPEER_A_LAST_HANDSHAKE_TIME: u16 = 6;
+const WGPEER_A_RX_BYTES: u16 = 7;
+const WGPEER_A_TX_BYTES: u16 = 8;
+const WGPEER_A_ALLOWEDIPS: u16 = 9;
+const WGALLOWEDIP_A_IPADDR: u16 = 2;
+const WGALLOWEDIP_A_CIDR_MASK: u16 = 3;
+const SOCKADDR_IN_LEN: usize = 16;
+const SOCKADDR_IN6_LEN: usize = 28;
+
+pub fn devices() -> io::Result<Vec<WireGuardDevice>> {
+    let indices: Vec<u32> = netlink::dump_links()?
+        .iter()
+        .filter(|link| super::linkinfo::link_kind(link) == Some(LinkKind::WireGuard))
+        .map(|link| link.header.index)
+        .collect();
+    if indices.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(netlink::dump_wireguard(&indices)?
+        .iter()
+        .filter_map(|msgs| parse_device(msgs))
+        .collect())
+}
+
+pub fn device_by_index(index: u32) -> io::Result<Option<WireGuardDevice>> {
+    let Some(link) = netlink::get_link(LinkSelector::Index(index))? else {
+        return Ok(None);
+    };
+    if super::linkinfo::link_kind(&link) != Some(LinkKind::WireGuard) {
+        return Ok(None);
+    }
+    Ok(netlink::dump_wireguard(&[index])?
+        .first()
+        .and_then(|msgs| parse_device(msgs)))
+}
+
+fn nlas(bytes: &[u8]) -> impl Iterator<Item = NlaBuffer<&[u8]>> {
+    NlasIterator::new(bytes).map_while(Result::ok)
+}
+
+fn key(value: &[u8]) -> Option<WireGuardKey> {
+    let bytes = <[u8; 32]>::try_from(value).ok()?;
+    // An all-zero public key means no private key is set.
+    (bytes != [0; 32]).then(|| WireGuardKey::new(bytes))
+}
+
+fn u16_value(value: &[u8]) -> Option<u16> {
+    <[u8; 2]>::try_from(value).ok().map(u16::from_ne_bytes)
+}
+
+fn u32_value(value: &[u8]) -> Option<u32> {
+    <[u8; 4]>::try_from(value).ok().map(u32::from_ne_bytes)
+}
+
+fn u64_value(value: &[u8]) -> Option<u64> {
+    <[u8; 8]>::try_from(value).ok().map(u64::from_ne_bytes)
+}
+
+/// Merges the messages of one device dump. A peer whose allowed IPs overflow a message is
+/// continued in the next one, repeating only its public key and the remaining allowed IPs.
+fn parse_device(msgs: &[Vec<u8>]) -> Option<WireGuardDevice> {
+    let mut device = WireGuardDevice {
+        index: 0,
+        name: String::new(),
+        public_key: None,
+        listen_port: None,
+        fwmark: None,
+        peers: Vec::new(),
+    };
+    for msg in msgs {
+        for nla in nlas(msg) {
+            let value = nla.value();
+            match nla.kind() {
+                WGDEVICE_A_IFINDEX => device.index = u32_value(value).unwrap_or_default(),
+                WGDEVICE_A_IFNAME => {
+                    device.name = String::from_utf8_lossy(value)
+                        .trim_end_matches('\0')
+                        .to_string()
+                }
+                WGDEVICE_A_PUBLIC_KEY => device.public_key = key(value),
+                WGDEVICE_A_LISTEN_PORT => {
+                    device.listen_port = u16_value(value).filter(|port| *port != 0)
+                }
+                WGDEVICE_A_FWMARK => device.fwmark = u32_value(value).filter(|mark| *mark != 0),
+                WGDEVICE_A_PEERS => {
+                    for peer in nlas(value).filter_map(|nla| parse_peer(nla.value())) {
+                        match device.peers.last_mut() {
+                            Some(last) if last.public_key == peer.public_key => {
+                                last.allowed_ips.extend(peer.allowed_ips)
+                            }
+                            _ => device.peers.push(peer),
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    (device.index != 0).then_some(device)
+}
+
+fn parse_peer(value: &[u8]) -> Option<WireGuardPeer> {
+    let mut public_key = None;
+    let mut peer = WireGuardPeer {
+        public_key: WireGuardKey::new([0; 32]),
+        endpoint: None,
+        allowed_ips: Vec::new(),
+        latest_handshake: None,
+        rx_bytes: 0,
+        tx_bytes: 0,
+        persistent_keepalive: None,
+    };
+    for nla in nlas(value) {
+        let value = nla.value();
+        match nla.kind() {
+            WGPEER_A_PUBLIC_KEY => public_key = <[u8; 32]>::try_from(value).ok(),
+            WGPEER_A_ENDPOINT => peer.endpoint = endpoint(value),
+            WGPEER_A_PERSISTENT_KEEPALIVE_INTERVAL => {
+                peer.persistent_keepalive = u16_value(value)
+                    .filter(|secs| *secs != 0)
+                    .map(|secs| Duration::from_secs(secs.into()))
+            }
+            WGPEER_A_LAST_HANDSHAKE_TIME => peer.latest_handshake = handshake_time(value),
+            WGPEER_A_RX_BYTES => peer.rx_bytes = u64_value(value).unwrap_or_default(),
+            WGPEER_A_TX_BYTES => peer.tx_bytes = u64_value(value).unwrap_or_default(),
+            WGPEER_A_ALLOWEDIPS => {
+                peer.allowed_ips = nlas(value)
+                    .filter_map(|nla| allowed_ip(nla.value()))
+                    .collect()
+            }
+            _ => {}
+        }
+    }
+    peer.public_key = WireGuardKey::new(public_key?);
+    Some(peer)
+}
+
+/// Parses a `struct sockaddr_in` or `struct sockaddr_in6`.
+fn endpoint(value: &[u8]) -> Option<SocketAddr> {
+    let family = u16_value(value.get(..2)?)?;
+    let port = u16::from_be_bytes([*value.get(2)?, *value.get(3)?]);
+    match i32::from(family) {
+        libc::AF_INET if value.len() >= SOCKADDR_IN_LEN => {
+            let ip = <[u8; 4]>::try_from(&value[4..8]).ok()?;
+            Some(SocketAddrV4::new(Ipv4Addr::from(ip), port).into())
+        }
+        libc::AF_INET6 if value.len() >= SOCKADDR_IN6_LEN => {
+            let flowinfo = u32_value(&value[4..8])?;
+            let ip = <[u8; 16]>::try_from(&value[8..24]).ok()?;
+            let scope_id = u32_value(&value[24..28])?;
+            Some(SocketAddrV6::new(Ipv6Addr::from(ip), port, flowinfo, scope_id).into())
+        }
+        _ => None,
+    }
+}
+
+/// Parses a `struct __kernel_timespec`, which is zero before the first handshake.
+fn handshake_time(value: &[u8]) -> Option<SystemTime> {
+    let secs = u64_value(value.get(..8)?)?;
+    let nanos = u64_value(value.get(8..16)?)?;
+    if secs == 0 && nanos == 0 {
+        return None;
+    }
+    SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(secs) + Duration::from_nanos(nanos))
+}
+
+fn allowed_ip(value: &[u8]) -> Option<IpNet> {
+    let mut addr = None;
+    let mut prefix = None;
+    for nla in nlas(value) {
+        match nla.kind() {
+            WGALLOWEDIP_A_IPADDR => {
+                addr = match nla.value().len() {
+                    4 => <[u8; 4]>::try_from(nla.value()).ok().map(IpAddr::from),
+                    16 => <[u8; 16]>::try_from(nla.value()).ok().map(IpAddr::from),
+                    _ => None,
+                }
+            }
+            WGALLOWEDIP_A_CIDR_MASK => prefix = nla.value().first().copied(),
+            _ => {}
+        }
+    }
+    IpNet::new(addr?, prefix?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_device;
+    use crate::net::wireguard::WireGuardKey;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::time::{Duration, SystemTime};
+
+    fn attr(kind: u16, value: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend(((value.len() + 4) as u16).to_ne_bytes());
+        out.extend(kind.to_ne_bytes());
+        out.extend(value);
+        out.resize(out.len().next_multiple_of(4), 0);
+        out
+    }
+
+    fn allowed_ip(ip: [u8; 4], prefix: u8) -> Vec<u8> {
+        let mut value = attr(1, &(libc::AF_INET as u16).to_ne_bytes());
+        value.extend(attr(2, &ip));
+        value.extend(attr(3, &[prefix]));
+        attr(0x8000, &value)
+    }
+
+    #[test]
+    fn merges_peers_split_across_messages() {
+        let mut endpoint = Vec::new();
+        endpoint.extend((libc::AF_INET as u16).to_ne_bytes());
+        endpoint.extend(51820u16.to_be_bytes());
+        endpoint.extend([203, 0, 113, 7]);
+        endpoint.extend([0; 8]);
+        let mut handshake = Vec::new();
+        handshake.extend(1_700_000_000u64.to_ne_bytes());
+        handshake.extend(0u64.to_ne_bytes());
+
+        let mut peer = attr(1, &[7; 32]);
+        peer.extend(attr(4, &endpoint));
+        peer.extend(attr(5, &25u16.to_ne_bytes()));
+        peer.extend(attr(6, &handshake));
+        peer.extend(attr(7, &1024u64.to_ne_bytes()));
+        peer.extend(attr(8, &2048u64.to_ne_bytes()));
+        peer.extend(attr(9, &allowed_ip([10, 0, 0, 2], 32)));
+        let mut first = attr(1, &5u32.to_ne_bytes());
+        first.extend(attr(2, b"wg0\0"));
+        first.extend(attr(4, &[9; 32]));
+        first.extend(attr(6, &51820u16.to_ne_bytes()));
+        first.extend(attr(7, &0u32.to_ne_bytes()));
+        first.extend(attr(8, &attr(0x8000, &peer)));
+
+        let mut continued = attr(1, &[7; 32]);
+        continued.extend(attr(9, &allowed_ip([192, 168, 10, 0], 24)));
+        let mut second = attr(1, &[8; 32]);
+        second.extend(attr(9, &allowed_ip([10, 0, 0, 3], 32)));
+        let mut peers = attr(0x8000, &continued);
+        peers.extend(attr(0x8001, &second));
+        let next = attr(8, &peers);
+
+        let device = parse_device(&[first, next]).expect("device");
+        assert_eq!((device.index, device.name.as_str()), (5, "wg0"));
+        assert_eq!(device.public_key, Some(WireGuardKey::new([9; 32])));
+        assert_eq!((device.listen_port, device.fwmark), (Some(51820), None));
+        assert_eq!(device.peers.len(), 2);
+
+        let peer = &device.peers[0];
+        assert_eq!(peer.public_key, WireGuardKey::new([7; 32]));
+        assert_eq!(
+            peer.endpoint,
+            Some(SocketAddr::from((Ipv4Addr::new(203, 0, 113, 7), 51820)))
+        );
+        assert_eq!(
+            peer.allowed_ips,
+            vec![
+                "10.0.0.2/32".parse().unwrap(),
+                "192.168.10.0/24".parse().unwrap()
+            ]
+        );
+        assert_eq!(
+            peer.latest_handshake,
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+        assert_eq!((peer.rx_bytes, peer.tx_bytes), (1024, 2048));
+        assert_eq!(peer.persistent_keepalive, Some(Duration::from_secs(25)));
+        assert_eq!(device.peers[1].latest_handshake, None);
+    }
+}
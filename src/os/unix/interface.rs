@@ -146,6 +146,8 @@ fn unix_interfaces_inner(
                 friendly_name: None,
                 description: None,
                 if_type: if_type,
+                link_kind: None,
+                tunnel: None,
                 mac_addr: mac,
                 ipv4: match ini_ipv4 {
                     Some(ipv4_addr) => vec![ipv4_addr],
@@ -171,10 +173,16 @@ fn unix_interfaces_inner(
                 #[cfg(feature = "gateway")]
                 gateway: None,
                 #[cfg(feature = "gateway")]
+                gateways: Vec::new(),
+                #[cfg(feature = "gateway")]
                 dns_servers: Vec::new(),
                 mtu,
                 #[cfg(feature = "gateway")]
                 default: false,
+                #[cfg(feature = "gateway")]
+                default_v4: false,
+                #[cfg(feature = "gateway")]
+                default_v6: false,
             };
             ifaces.push(interface);
         }
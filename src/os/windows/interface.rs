@@ -362,6 +362,8 @@ pub fn interfaces() -> Vec<Interface> {
                 friendly_name: Some(unsafe { from_wide_string(cur.FriendlyName) }),
                 description: Some(unsafe { from_wide_string(cur.Description) }),
                 if_type,
+                link_kind: None,
+                tunnel: None,
                 mac_addr,
                 ipv4: ipv4_vec,
                 ipv6: ipv6_vec,
@@ -380,10 +382,16 @@ pub fn interfaces() -> Vec<Interface> {
                 #[cfg(feature = "gateway")]
                 gateway: default_gateway,
                 #[cfg(feature = "gateway")]
+                gateways: Vec::new(),
+                #[cfg(feature = "gateway")]
                 dns_servers,
                 mtu: Some(cur.Mtu),
                 #[cfg(feature = "gateway")]
                 default,
+                #[cfg(feature = "gateway")]
+                default_v4: false,
+                #[cfg(feature = "gateway")]
+                default_v6: false,
             };
             Some(interface)
         })
@@ -1,4 +1,4 @@
-use crate::stats::counters::InterfaceStats;
+use crate::stats::counters::{CounterWidth, InterfaceStats};
 
 pub(crate) fn get_stats_from_index(index: u32) -> Option<InterfaceStats> {
     use std::mem::zeroed;
@@ -14,6 +14,8 @@ pub(crate) fn get_stats_from_index(index: u32) -> Option<InterfaceStats> {
                 rx_bytes: row.InOctets as u64,
                 tx_bytes: row.OutOctets as u64,
                 timestamp: Some(SystemTime::now()),
+                counter_width: CounterWidth::Bits64,
+                extended: None,
             })
         } else {
             None
@@ -0,0 +1,37 @@
+use std::net::IpAddr;
+
+/// Parameters for a kernel route lookup.
+///
+/// Only `destination` is required. The optional fields narrow the lookup the same way the
+/// corresponding selectors of `ip route get` do, which matters on hosts with policy routing.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RouteQuery {
+    /// Destination address to resolve.
+    pub destination: IpAddr,
+    /// Source address the traffic would use.
+    ///
+    /// Must belong to the same address family as `destination`.
+    pub source: Option<IpAddr>,
+    /// Firewall mark (`fwmark`) carried by the traffic.
+    pub mark: Option<u32>,
+    /// Index of the interface the traffic would arrive on.
+    ///
+    /// Setting this simulates forwarding of a received packet instead of locally
+    /// originated traffic.
+    pub input_if: Option<u32>,
+    /// Index of the interface the traffic is forced to leave through.
+    pub output_if: Option<u32>,
+}
+
+impl RouteQuery {
+    /// Creates a query for `destination` without additional selectors.
+    pub fn new(destination: IpAddr) -> RouteQuery {
+        RouteQuery {
+            destination,
+            source: None,
+            mark: None,
+            input_if: None,
+            output_if: None,
+        }
+    }
+}
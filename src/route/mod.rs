@@ -1,23 +1,207 @@
+pub mod lookup;
+pub mod rule;
+pub mod table;
+pub mod types;
+
+use crate::error::Error;
 use crate::interface::interface::Interface;
-use crate::net::device::NetworkDevice;
+use crate::net::device::{Gateway, NetworkDevice};
+use crate::route::table::RT_TABLE_MAIN;
 use std::net::IpAddr;
 
-/// Returns the default gateway associated with the active default interface.
-///
-/// Returns an error when the local IP address cannot be determined, when no matching interface
-/// is found, or when the platform does not provide gateway information for the default route.
-pub fn get_default_gateway() -> Result<NetworkDevice, String> {
-    let local_ip: IpAddr = match crate::net::ip::get_local_ipaddr() {
-        Some(local_ip) => local_ip,
-        None => return Err(String::from("Local IP address not found")),
-    };
-    let interfaces: Vec<Interface> = crate::interface::get_interfaces();
-    for iface in interfaces {
-        if crate::interface::iface_has_ip(&iface, local_ip) {
-            if let Some(gateway) = iface.gateway {
-                return Ok(gateway);
-            }
+pub use self::lookup::RouteQuery;
+pub use self::rule::RoutingRule;
+pub use self::table::{NextHop, Route};
+
+/// Returns the default gateway with the lowest route metric.
+///
+/// Default routes of every interface are compared, and ties prefer the interface selected as
+/// the default route and then IPv4. On Linux only the main routing table is considered, so
+/// default routes of policy routing tables and VRFs do not take precedence. The returned
+/// device carries the link-layer address of the selected router. On platforms that do not
+/// report route metrics, the gateway of the active default interface is returned.
+///
+/// Returns `Error::NoDefaultRoute` when the default interface cannot be determined or when the
+/// platform does not provide gateway information for the default route.
+pub fn get_default_gateway() -> Result<NetworkDevice, Error> {
+    let ifaces = crate::interface::interfaces();
+    if let Some((pos, gateway)) = pick_default_gateway(&ifaces)
+        && let Some(mut device) = ifaces[pos].gateway.clone()
+    {
+        if let Some(mac_addr) = gateway.mac_addr {
+            device.mac_addr = mac_addr;
+        }
+        return Ok(device);
+    }
+    let iface: Interface = crate::interface::resolve_default_interface(ifaces)?;
+    iface.gateway.ok_or(Error::NoDefaultRoute)
+}
+
+/// Returns the position of the interface owning the lowest-metric gateway of the main table,
+/// with that gateway.
+fn pick_default_gateway(ifaces: &[Interface]) -> Option<(usize, &Gateway)> {
+    ifaces
+        .iter()
+        .enumerate()
+        .flat_map(|(pos, iface)| iface.gateways.iter().map(move |g| (pos, iface, g)))
+        // Metrics of different tables are not comparable, as only rules select between tables
+        .filter(|(_, _, g)| g.table.is_none_or(|table| table == RT_TABLE_MAIN))
+        .filter_map(|(pos, iface, g)| {
+            let metric = g.metric?;
+            Some(((metric, !iface.default, g.ip_addr.is_ipv6()), pos, g))
+        })
+        .min_by_key(|(key, _, _)| *key)
+        .map(|(_, pos, g)| (pos, g))
+}
+
+/// Returns every entry of the system routing tables.
+///
+/// On Linux this includes routes from all tables, not only the main table. Filter on
+/// `Route::table` to restrict the result.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the route dump fails, and
+/// with `Error::Unsupported` on platforms where the routing table cannot be read.
+pub fn get_routes() -> Result<Vec<Route>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::route::routes().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+/// Returns the policy routing rules for IPv4 and IPv6.
+///
+/// Rules are ordered by family (IPv4 first) and then by priority, which is the order in which
+/// the kernel evaluates them.
+///
+/// Fails with `Error::Netlink` or `Error::PermissionDenied` when the rule dump fails, and
+/// with `Error::Unsupported` on platforms without policy routing.
+pub fn get_rules() -> Result<Vec<RoutingRule>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::route::rules().map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err(Error::Unsupported)
+    }
+}
+
+/// Asks the kernel which route it would select for traffic to `destination`.
+///
+/// Unlike a routing table scan, this honors policy routing rules, so the result reflects
+/// VPN split tunnels and VRF setups. The returned route carries the selected output interface,
+/// gateway, preferred source address, and table. No traffic is sent.
+///
+/// Fails with `Error::Netlink` carrying the kernel error when the destination is unreachable,
+/// or with `Error::PermissionDenied` when a prohibit route matches, as the kernel reports
+/// those with `EACCES`. Returns `Error::Unsupported` on platforms without kernel route lookups.
+pub fn lookup(destination: IpAddr) -> Result<Route, Error> {
+    lookup_with(&RouteQuery::new(destination))
+}
+
+/// Performs a kernel route lookup with additional selectors.
+///
+/// See `lookup` for details.
+pub fn lookup_with(query: &RouteQuery) -> Result<Route, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::route::lookup(query).map_err(Error::netlink)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = query;
+        Err(Error::Unsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pick_default_gateway;
+    use crate::interface::interface::Interface;
+    use crate::net::device::Gateway;
+    use crate::route::table::RT_TABLE_MAIN;
+
+    fn iface_with(index: u32, gateways: Vec<Gateway>) -> Interface {
+        let mut iface = Interface::dummy();
+        iface.index = index;
+        iface.gateways = gateways;
+        iface
+    }
+
+    fn gateway(ip: &str, metric: Option<u32>) -> Gateway {
+        Gateway {
+            metric,
+            ..Gateway::new(ip.parse().unwrap())
         }
     }
-    Err(String::from("Default Gateway not found"))
+
+    #[test]
+    fn picks_lowest_metric_across_interfaces() {
+        let ifaces = vec![
+            iface_with(2, vec![gateway("192.0.2.1", Some(600))]),
+            iface_with(
+                3,
+                vec![
+                    gateway("198.51.100.1", Some(100)),
+                    gateway("fe80::1", Some(1024)),
+                ],
+            ),
+        ];
+
+        let (pos, gw) = pick_default_gateway(&ifaces).unwrap();
+
+        assert_eq!(ifaces[pos].index, 3);
+        assert_eq!(
+            gw.ip_addr,
+            "198.51.100.1".parse::<std::net::IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn breaks_ties_with_default_interface_then_ipv4() {
+        let mut ifaces = vec![
+            iface_with(2, vec![gateway("192.0.2.1", Some(0))]),
+            iface_with(
+                3,
+                vec![
+                    gateway("fe80::1", Some(0)),
+                    gateway("198.51.100.1", Some(0)),
+                ],
+            ),
+        ];
+        ifaces[1].default = true;
+
+        let (pos, gw) = pick_default_gateway(&ifaces).unwrap();
+
+        assert_eq!(ifaces[pos].index, 3);
+        assert!(gw.ip_addr.is_ipv4());
+    }
+
+    #[test]
+    fn ignores_default_routes_outside_the_main_table() {
+        let vpn = Gateway {
+            table: Some(51820),
+            ..gateway("10.8.0.1", Some(0))
+        };
+        let main = Gateway {
+            table: Some(RT_TABLE_MAIN),
+            ..gateway("192.0.2.1", Some(100))
+        };
+        let ifaces = vec![iface_with(2, vec![main]), iface_with(5, vec![vpn])];
+
+        let (pos, gw) = pick_default_gateway(&ifaces).unwrap();
+
+        assert_eq!(ifaces[pos].index, 2);
+        assert_eq!(gw.table, Some(RT_TABLE_MAIN));
+    }
+
+    #[test]
+    fn ignores_gateways_without_metrics() {
+        let ifaces = vec![iface_with(2, vec![gateway("192.0.2.1", None)])];
+        assert!(pick_default_gateway(&ifaces).is_none());
+    }
 }
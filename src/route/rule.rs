@@ -0,0 +1,97 @@
+use super::types::IpFamily;
+use ipnet::IpNet;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Action taken when a routing rule matches.
+///
+/// Values follow the Linux `FR_ACT_*` identifiers.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RuleAction {
+    /// Action is not specified.
+    Unspec,
+    /// Look the destination up in `RoutingRule::table`.
+    ToTable,
+    /// Continue with the rule at priority `RoutingRule::goto`.
+    Goto,
+    /// Do nothing and continue with the next rule.
+    Nop,
+    /// Silently discard the packet.
+    Blackhole,
+    /// Reject the packet as unreachable.
+    Unreachable,
+    /// Reject the packet as administratively prohibited.
+    Prohibit,
+    /// Unrecognized action value.
+    Other(u8),
+}
+
+impl From<u8> for RuleAction {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => RuleAction::Unspec,
+            1 => RuleAction::ToTable,
+            2 => RuleAction::Goto,
+            3 => RuleAction::Nop,
+            6 => RuleAction::Blackhole,
+            7 => RuleAction::Unreachable,
+            8 => RuleAction::Prohibit,
+            _ => RuleAction::Other(v),
+        }
+    }
+}
+
+impl fmt::Display for RuleAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleAction::Unspec => write!(f, "unspec"),
+            RuleAction::ToTable => write!(f, "lookup"),
+            RuleAction::Goto => write!(f, "goto"),
+            RuleAction::Nop => write!(f, "nop"),
+            RuleAction::Blackhole => write!(f, "blackhole"),
+            RuleAction::Unreachable => write!(f, "unreachable"),
+            RuleAction::Prohibit => write!(f, "prohibit"),
+            RuleAction::Other(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// A policy routing rule, as listed by `ip rule`.
+///
+/// Selector fields are `None` when the rule does not match on them.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoutingRule {
+    /// Address family the rule applies to.
+    pub family: IpFamily,
+    /// Rule priority. Rules are evaluated in ascending order.
+    pub priority: u32,
+    /// Source prefix selector.
+    pub source: Option<IpNet>,
+    /// Destination prefix selector.
+    pub destination: Option<IpNet>,
+    /// Whether the selectors are inverted (`not` in `ip rule`).
+    pub invert: bool,
+    /// Input interface name selector.
+    pub iif: Option<String>,
+    /// Output interface name selector.
+    pub oif: Option<String>,
+    /// Firewall mark selector.
+    pub fwmark: Option<u32>,
+    /// Mask applied to the firewall mark before comparison.
+    pub fwmask: Option<u32>,
+    /// Socket owner UID range selector.
+    pub uid_range: Option<RangeInclusive<u32>>,
+    /// Whether the table is taken from the L3 master device (VRF) of the interface.
+    pub l3mdev: bool,
+    /// Action taken when the rule matches.
+    pub action: RuleAction,
+    /// Routing table used by `RuleAction::ToTable`.
+    pub table: Option<u32>,
+    /// Target priority used by `RuleAction::Goto`.
+    pub goto: Option<u32>,
+}
@@ -0,0 +1,198 @@
+use super::types::{RouteProtocol, RouteScope, RouteType};
+use ipnet::IpNet;
+use std::net::IpAddr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Routing table identifier of the Linux `main` table.
+pub const RT_TABLE_MAIN: u32 = 254;
+/// Routing table identifier of the Linux `local` table.
+pub const RT_TABLE_LOCAL: u32 = 255;
+
+/// One path of a multipath (ECMP) route.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NextHop {
+    /// Gateway address of this path, when it is not directly connected.
+    pub gateway: Option<IpAddr>,
+    /// Index of the output interface of this path.
+    pub if_index: u32,
+    /// Name of the output interface, when it could be resolved.
+    pub if_name: Option<String>,
+    /// Relative weight used to balance traffic across paths. The minimum is `1`.
+    pub weight: u16,
+    /// Raw next-hop flags (`RTNH_F_*` on Linux).
+    pub flags: u8,
+}
+
+impl NextHop {
+    /// Returns `true` when the kernel considers this path unusable.
+    pub fn is_dead(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+    /// Returns `true` when the gateway is assumed to be on-link.
+    pub fn is_onlink(&self) -> bool {
+        self.flags & 0x04 != 0
+    }
+    /// Returns `true` when the carrier of the output interface is down.
+    pub fn is_linkdown(&self) -> bool {
+        self.flags & 0x10 != 0
+    }
+}
+
+/// A single entry of the system routing table.
+///
+/// Values are collected from platform-specific routing APIs.
+/// Some metadata is optional.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Route {
+    /// Destination network, including prefix length.
+    ///
+    /// Default routes use the unspecified address with a prefix length of `0`.
+    pub destination: IpNet,
+    /// Next-hop gateway address, when the route is not directly connected.
+    ///
+    /// This field is `None` for multipath routes. See `Route::nexthops`.
+    pub gateway: Option<IpAddr>,
+    /// Index of the output interface, when the route is bound to one.
+    ///
+    /// This field is `None` for multipath routes. See `Route::nexthops`.
+    pub if_index: Option<u32>,
+    /// Name of the output interface, when it could be resolved.
+    pub if_name: Option<String>,
+    /// Route metric (priority). Lower values are preferred.
+    pub metric: Option<u32>,
+    /// Routing table identifier.
+    ///
+    /// On Linux the main table is `RT_TABLE_MAIN` and the local table is `RT_TABLE_LOCAL`.
+    pub table: u32,
+    /// Origin of the route.
+    pub protocol: RouteProtocol,
+    /// Distance to the destination.
+    pub scope: RouteScope,
+    /// Forwarding behavior of the route.
+    pub route_type: RouteType,
+    /// Preferred source address for traffic using this route.
+    pub pref_source: Option<IpAddr>,
+    /// Route-specific MTU in bytes, when one is configured.
+    pub mtu: Option<u32>,
+    /// Paths of a multipath (ECMP) route.
+    ///
+    /// The vector is empty for single-path routes, which use `Route::gateway` and
+    /// `Route::if_index` instead.
+    pub nexthops: Vec<NextHop>,
+}
+
+impl Route {
+    /// Returns `true` when this route matches every destination of its address family.
+    pub fn is_default(&self) -> bool {
+        self.destination.prefix_len() == 0
+    }
+    /// Returns `true` when this route is for an IPv4 destination.
+    pub fn is_ipv4(&self) -> bool {
+        matches!(self.destination, IpNet::V4(_))
+    }
+    /// Returns `true` when this route is for an IPv6 destination.
+    pub fn is_ipv6(&self) -> bool {
+        matches!(self.destination, IpNet::V6(_))
+    }
+    /// Returns `true` when this route balances traffic across several paths.
+    pub fn is_multipath(&self) -> bool {
+        !self.nexthops.is_empty()
+    }
+    /// Returns every path of this route.
+    ///
+    /// For single-path routes this returns one entry built from `Route::gateway` and
+    /// `Route::if_index` with a weight of `1`. Routes without an output interface, such as
+    /// blackhole routes, return an empty vector.
+    pub fn paths(&self) -> Vec<NextHop> {
+        if self.is_multipath() {
+            return self.nexthops.clone();
+        }
+        match self.if_index {
+            Some(if_index) => vec![NextHop {
+                gateway: self.gateway,
+                if_index,
+                if_name: self.if_name.clone(),
+                weight: 1,
+                flags: 0,
+            }],
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NextHop, Route};
+    use crate::route::types::{RouteProtocol, RouteScope, RouteType};
+
+    fn default_route() -> Route {
+        Route {
+            destination: "0.0.0.0/0".parse().unwrap(),
+            gateway: None,
+            if_index: None,
+            if_name: None,
+            metric: None,
+            table: super::RT_TABLE_MAIN,
+            protocol: RouteProtocol::Static,
+            scope: RouteScope::Universe,
+            route_type: RouteType::Unicast,
+            pref_source: None,
+            mtu: None,
+            nexthops: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn single_path_route_yields_one_path() {
+        let mut rt = default_route();
+        rt.gateway = Some("192.0.2.1".parse().unwrap());
+        rt.if_index = Some(2);
+
+        let paths = rt.paths();
+
+        assert!(rt.is_default());
+        assert!(!rt.is_multipath());
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].gateway, rt.gateway);
+        assert_eq!(paths[0].weight, 1);
+    }
+
+    #[test]
+    fn multipath_route_yields_every_nexthop() {
+        let mut rt = default_route();
+        rt.nexthops = vec![
+            NextHop {
+                gateway: Some("192.0.2.1".parse().unwrap()),
+                if_index: 2,
+                if_name: None,
+                weight: 1,
+                flags: 0,
+            },
+            NextHop {
+                gateway: Some("198.51.100.1".parse().unwrap()),
+                if_index: 3,
+                if_name: None,
+                weight: 3,
+                flags: 0x10,
+            },
+        ];
+
+        let paths = rt.paths();
+
+        assert!(rt.is_multipath());
+        assert_eq!(paths.len(), 2);
+        assert!(paths[1].is_linkdown());
+        assert!(!paths[1].is_dead());
+    }
+
+    #[test]
+    fn blackhole_route_has_no_paths() {
+        let mut rt = default_route();
+        rt.route_type = RouteType::Blackhole;
+        assert!(rt.paths().is_empty());
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// IP address family.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IpFamily {
+    /// IPv4.
+    V4,
+    /// IPv6.
+    V6,
+}
+
+/// Origin of a route, as reported by the routing subsystem.
+///
+/// Values follow the Linux `RTPROT_*` identifiers.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RouteProtocol {
+    /// Origin is not specified.
+    Unspec,
+    /// Route was installed by an ICMP redirect.
+    Redirect,
+    /// Route was installed by the kernel, for example for a directly connected prefix.
+    Kernel,
+    /// Route was installed during boot.
+    Boot,
+    /// Route was installed by an administrator.
+    Static,
+    /// Route was learned from IPv6 router advertisements.
+    Ra,
+    /// Route was installed by a DHCP client.
+    Dhcp,
+    /// Route was installed by a routing daemon such as BIRD, FRR, or keepalived.
+    Daemon(u8),
+    /// Unrecognized protocol value.
+    Other(u8),
+}
+
+impl RouteProtocol {
+    /// Returns the native numeric protocol identifier.
+    pub fn value(&self) -> u8 {
+        match *self {
+            RouteProtocol::Unspec => 0,
+            RouteProtocol::Redirect => 1,
+            RouteProtocol::Kernel => 2,
+            RouteProtocol::Boot => 3,
+            RouteProtocol::Static => 4,
+            RouteProtocol::Ra => 9,
+            RouteProtocol::Dhcp => 16,
+            RouteProtocol::Daemon(v) | RouteProtocol::Other(v) => v,
+        }
+    }
+}
+
+impl From<u8> for RouteProtocol {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => RouteProtocol::Unspec,
+            1 => RouteProtocol::Redirect,
+            2 => RouteProtocol::Kernel,
+            3 => RouteProtocol::Boot,
+            4 => RouteProtocol::Static,
+            9 => RouteProtocol::Ra,
+            16 => RouteProtocol::Dhcp,
+            // gated, mrt, zebra, bird, dnrouted, xorp, ntk
+            8 | 10..=15 => RouteProtocol::Daemon(v),
+            // mrouted, keepalived, babel, openr, bgp, isis, ospf, rip, eigrp
+            17 | 18 | 42 | 99 | 186..=189 | 192 => RouteProtocol::Daemon(v),
+            _ => RouteProtocol::Other(v),
+        }
+    }
+}
+
+impl fmt::Display for RouteProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteProtocol::Unspec => write!(f, "unspec"),
+            RouteProtocol::Redirect => write!(f, "redirect"),
+            RouteProtocol::Kernel => write!(f, "kernel"),
+            RouteProtocol::Boot => write!(f, "boot"),
+            RouteProtocol::Static => write!(f, "static"),
+            RouteProtocol::Ra => write!(f, "ra"),
+            RouteProtocol::Dhcp => write!(f, "dhcp"),
+            RouteProtocol::Daemon(v) | RouteProtocol::Other(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// Distance to the destination of a route.
+///
+/// Values follow the Linux `RT_SCOPE_*` identifiers.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RouteScope {
+    /// Destination is reachable through a gateway.
+    Universe,
+    /// Destination is within the local site.
+    Site,
+    /// Destination is on a directly attached link.
+    Link,
+    /// Destination is a local address of this host.
+    Host,
+    /// Destination does not exist.
+    Nowhere,
+    /// Unrecognized scope value.
+    Other(u8),
+}
+
+impl RouteScope {
+    /// Returns the native numeric scope identifier.
+    pub fn value(&self) -> u8 {
+        match *self {
+            RouteScope::Universe => 0,
+            RouteScope::Site => 200,
+            RouteScope::Link => 253,
+            RouteScope::Host => 254,
+            RouteScope::Nowhere => 255,
+            RouteScope::Other(v) => v,
+        }
+    }
+}
+
+impl From<u8> for RouteScope {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => RouteScope::Universe,
+            200 => RouteScope::Site,
+            253 => RouteScope::Link,
+            254 => RouteScope::Host,
+            255 => RouteScope::Nowhere,
+            _ => RouteScope::Other(v),
+        }
+    }
+}
+
+impl fmt::Display for RouteScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteScope::Universe => write!(f, "global"),
+            RouteScope::Site => write!(f, "site"),
+            RouteScope::Link => write!(f, "link"),
+            RouteScope::Host => write!(f, "host"),
+            RouteScope::Nowhere => write!(f, "nowhere"),
+            RouteScope::Other(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// Forwarding behavior of a route.
+///
+/// Values follow the Linux `RTN_*` identifiers.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RouteType {
+    /// Route type is not specified.
+    Unspec,
+    /// Gateway or directly connected route.
+    Unicast,
+    /// Destination is a local address.
+    Local,
+    /// Destination is a local broadcast address.
+    Broadcast,
+    /// Destination is a local anycast address.
+    Anycast,
+    /// Multicast route.
+    Multicast,
+    /// Packets are silently discarded.
+    Blackhole,
+    /// Destination is unreachable.
+    Unreachable,
+    /// Destination is administratively prohibited.
+    Prohibit,
+    /// Lookup continues in the next routing table.
+    Throw,
+    /// Network address translation route.
+    Nat,
+    /// Unrecognized route type value.
+    Other(u8),
+}
+
+impl RouteType {
+    /// Returns the native numeric route type identifier.
+    pub fn value(&self) -> u8 {
+        match *self {
+            RouteType::Unspec => 0,
+            RouteType::Unicast => 1,
+            RouteType::Local => 2,
+            RouteType::Broadcast => 3,
+            RouteType::Anycast => 4,
+            RouteType::Multicast => 5,
+            RouteType::Blackhole => 6,
+            RouteType::Unreachable => 7,
+            RouteType::Prohibit => 8,
+            RouteType::Throw => 9,
+            RouteType::Nat => 10,
+            RouteType::Other(v) => v,
+        }
+    }
+}
+
+impl From<u8> for RouteType {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => RouteType::Unspec,
+            1 => RouteType::Unicast,
+            2 => RouteType::Local,
+            3 => RouteType::Broadcast,
+            4 => RouteType::Anycast,
+            5 => RouteType::Multicast,
+            6 => RouteType::Blackhole,
+            7 => RouteType::Unreachable,
+            8 => RouteType::Prohibit,
+            9 => RouteType::Throw,
+            10 => RouteType::Nat,
+            _ => RouteType::Other(v),
+        }
+    }
+}
+
+impl fmt::Display for RouteType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteType::Unspec => write!(f, "unspec"),
+            RouteType::Unicast => write!(f, "unicast"),
+            RouteType::Local => write!(f, "local"),
+            RouteType::Broadcast => write!(f, "broadcast"),
+            RouteType::Anycast => write!(f, "anycast"),
+            RouteType::Multicast => write!(f, "multicast"),
+            RouteType::Blackhole => write!(f, "blackhole"),
+            RouteType::Unreachable => write!(f, "unreachable"),
+            RouteType::Prohibit => write!(f, "prohibit"),
+            RouteType::Throw => write!(f, "throw"),
+            RouteType::Nat => write!(f, "nat"),
+            RouteType::Other(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RouteProtocol, RouteScope, RouteType};
+
+    #[test]
+    fn round_trips_native_values() {
+        for v in 0..=u8::MAX {
+            assert_eq!(RouteProtocol::from(v).value(), v);
+            assert_eq!(RouteScope::from(v).value(), v);
+            assert_eq!(RouteType::from(v).value(), v);
+        }
+    }
+
+    #[test]
+    fn classifies_routing_daemons() {
+        assert_eq!(RouteProtocol::from(12), RouteProtocol::Daemon(12));
+        assert_eq!(RouteProtocol::from(186), RouteProtocol::Daemon(186));
+        assert_eq!(RouteProtocol::from(16), RouteProtocol::Dhcp);
+        assert_eq!(RouteProtocol::from(250), RouteProtocol::Other(250));
+    }
+}
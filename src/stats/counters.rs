@@ -20,6 +20,76 @@ pub struct InterfaceStats {
     ///
     /// This is `None` only when the platform-specific collector cannot provide a timestamp.
     pub timestamp: Option<SystemTime>,
+    /// Width of the counters as kept by the source, which decides where they wrap around.
+    pub counter_width: CounterWidth,
+    /// Packet, error, and drop counters, when the platform reports them.
+    ///
+    /// This is currently collected on Linux and Android.
+    pub extended: Option<ExtendedStats>,
+}
+
+/// Width of the counters reported by a statistics source.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CounterWidth {
+    /// Counters wrap at 2^32, as those of the Linux `IFLA_STATS` attribute do.
+    Bits32,
+    /// Counters wrap at 2^64, so in practice they only go back when reset.
+    Bits64,
+}
+
+/// Packet, error, and drop counters of an interface.
+///
+/// The fields follow the Linux `rtnl_link_stats64` layout. Counters that a source does not
+/// report are `0`. For example `/proc/net/dev` only reports aggregate error counts, so the
+/// detailed error fields stay `0` when it is the only available source.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtendedStats {
+    /// Total number of received packets.
+    pub rx_packets: u64,
+    /// Total number of transmitted packets.
+    pub tx_packets: u64,
+    /// Total number of bad packets received.
+    pub rx_errors: u64,
+    /// Total number of packets that failed to transmit.
+    pub tx_errors: u64,
+    /// Received packets dropped by the host, for example for lack of buffer space.
+    pub rx_dropped: u64,
+    /// Packets dropped on their way to transmission.
+    pub tx_dropped: u64,
+    /// Received multicast packets.
+    pub multicast: u64,
+    /// Collisions detected during transmission.
+    pub collisions: u64,
+    /// Received packets with an invalid length.
+    pub rx_length_errors: u64,
+    /// Receive ring buffer overflows.
+    pub rx_over_errors: u64,
+    /// Received packets with a CRC error.
+    pub rx_crc_errors: u64,
+    /// Received frames with an alignment error.
+    pub rx_frame_errors: u64,
+    /// Receive FIFO overruns.
+    pub rx_fifo_errors: u64,
+    /// Received packets missed by the host.
+    pub rx_missed_errors: u64,
+    /// Transmissions aborted by the device.
+    pub tx_aborted_errors: u64,
+    /// Transmissions failed because of carrier loss.
+    pub tx_carrier_errors: u64,
+    /// Transmit FIFO underruns.
+    pub tx_fifo_errors: u64,
+    /// Heartbeat (SQE test) errors.
+    pub tx_heartbeat_errors: u64,
+    /// Late collisions.
+    pub tx_window_errors: u64,
+    /// Received compressed packets.
+    pub rx_compressed: u64,
+    /// Transmitted compressed packets.
+    pub tx_compressed: u64,
+    /// Received packets dropped because no protocol handler was registered.
+    pub rx_nohandler: u64,
 }
 
 #[cfg(target_vendor = "apple")]
@@ -36,6 +106,8 @@ pub(crate) fn get_stats(ifa: Option<&libc::ifaddrs>, _name: &str) -> Option<Inte
                 rx_bytes: data.ifi_ibytes as u64,
                 tx_bytes: data.ifi_obytes as u64,
                 timestamp: Some(SystemTime::now()),
+                counter_width: CounterWidth::Bits64,
+                extended: None,
             })
         } else {
             None
@@ -52,18 +124,14 @@ pub(crate) fn get_stats(_ifa: Option<&libc::ifaddrs>, name: &str) -> Option<Inte
 
 #[cfg(target_os = "linux")]
 pub(crate) fn get_stats_from_name(name: &str) -> Option<InterfaceStats> {
-    use std::fs::read_to_string;
-    let rx_path = format!("/sys/class/net/{}/statistics/rx_bytes", name);
-    let tx_path = format!("/sys/class/net/{}/statistics/tx_bytes", name);
-
-    let rx_bytes = read_to_string(rx_path).ok()?.trim().parse::<u64>().ok()?;
-    let tx_bytes = read_to_string(tx_path).ok()?.trim().parse::<u64>().ok()?;
+    crate::os::linux::stats::stats_by_name(name).or_else(|| stats_fallback(name))
+}
 
-    Some(InterfaceStats {
-        rx_bytes,
-        tx_bytes,
-        timestamp: Some(SystemTime::now()),
-    })
+/// Reads the counters of `name` from the filesystem when netlink is unavailable.
+#[cfg(target_os = "linux")]
+fn stats_fallback(name: &str) -> Option<InterfaceStats> {
+    crate::os::linux::stats::stats_from_sysfs(name)
+        .or_else(|| crate::os::linux::stats::stats_from_proc(name))
 }
 
 #[cfg(target_os = "android")]
@@ -84,6 +152,8 @@ pub(crate) fn get_stats_from_name(name: &str) -> Option<InterfaceStats> {
         rx_bytes,
         tx_bytes,
         timestamp: Some(SystemTime::now()),
+        counter_width: CounterWidth::Bits64,
+        extended: None,
     })
 }
 
@@ -129,6 +199,8 @@ fn get_stats_from_name(name: &str) -> Option<InterfaceStats> {
         rx_bytes: data.ifmd_data.ifi_ibytes,
         tx_bytes: data.ifmd_data.ifi_obytes,
         timestamp: Some(SystemTime::now()),
+        counter_width: CounterWidth::Bits64,
+        extended: None,
     })
 }
 
@@ -164,6 +236,8 @@ fn get_stats_from_name(name: &str) -> Option<InterfaceStats> {
                         rx_bytes: data.ifi_ibytes as u64,
                         tx_bytes: data.ifi_obytes as u64,
                         timestamp: Some(SystemTime::now()),
+                        counter_width: CounterWidth::Bits64,
+                        extended: None,
                     });
                     break;
                 }
@@ -196,6 +270,8 @@ pub(crate) fn get_stats_from_index(index: u32) -> Option<InterfaceStats> {
                 rx_bytes: row.InOctets as u64,
                 tx_bytes: row.OutOctets as u64,
                 timestamp: Some(SystemTime::now()),
+                counter_width: CounterWidth::Bits64,
+                extended: None,
             })
         } else {
             None
@@ -204,7 +280,12 @@ pub(crate) fn get_stats_from_index(index: u32) -> Option<InterfaceStats> {
 }
 
 pub(crate) fn update_interface_stats(iface: &mut Interface) -> std::io::Result<()> {
-    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[cfg(target_os = "linux")]
+    {
+        iface.stats = crate::os::linux::stats::stats_by_index(iface.index)
+            .or_else(|| stats_fallback(iface.name.as_str()));
+    }
+    #[cfg(target_os = "android")]
     {
         iface.stats = get_stats_from_name(iface.name.as_str());
     }
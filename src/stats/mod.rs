@@ -1 +1,50 @@
 pub mod counters;
+pub mod sampler;
+
+use crate::error::Error;
+use crate::interface::interface::Interface;
+use crate::stats::counters::InterfaceStats;
+use std::collections::BTreeMap;
+
+/// Returns the counters of every interface, keyed by interface index.
+///
+/// On Linux and Android this takes a single `RTM_GETLINK` dump instead of reading sysfs
+/// files per interface. Linux falls back to one read of `/proc/net/dev` when netlink is
+/// unavailable. Other platforms collect the counters during a regular enumeration.
+///
+/// Interfaces whose counters are unavailable are omitted.
+///
+/// On Linux and Android this fails with `Error::Netlink` or `Error::PermissionDenied` when
+/// the link dump fails and, on Linux, `/proc/net/dev` cannot be read either. Other platforms
+/// currently never fail.
+pub fn get_all_stats() -> Result<BTreeMap<u32, InterfaceStats>, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::stats::all_stats().map_err(Error::netlink)
+    }
+    #[cfg(target_os = "android")]
+    {
+        crate::os::android::netlink::dump_links()
+            .map(|links| crate::os::linux::stats::stats_from_links(&links))
+            .map_err(Error::netlink)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        Ok(crate::interface::interfaces()
+            .into_iter()
+            .filter_map(|iface| Some((iface.index, iface.stats?)))
+            .collect())
+    }
+}
+
+/// Refreshes `Interface::stats` of every interface in `ifaces` with one `get_all_stats` call.
+///
+/// Interfaces missing from the new snapshot, for example because they were removed, get
+/// `None`. Fails like `get_all_stats`, leaving `ifaces` unchanged.
+pub fn update_all_stats(ifaces: &mut [Interface]) -> Result<(), Error> {
+    let mut all = get_all_stats()?;
+    for iface in ifaces {
+        iface.stats = all.remove(&iface.index);
+    }
+    Ok(())
+}
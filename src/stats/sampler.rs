@@ -0,0 +1,451 @@
+use crate::interface::interface::Interface;
+use crate::interface::types::InterfaceType;
+use crate::stats::counters::{CounterWidth, InterfaceStats};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Per-second rates computed from two successive `InterfaceStats` snapshots.
+///
+/// Packet, error, and drop rates are `None` when either snapshot lacks
+/// `InterfaceStats::extended`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rates {
+    /// Time between the two snapshots.
+    pub interval: Duration,
+    /// Received bytes per second.
+    pub rx_bytes_per_sec: f64,
+    /// Transmitted bytes per second.
+    pub tx_bytes_per_sec: f64,
+    /// Received packets per second.
+    pub rx_packets_per_sec: Option<f64>,
+    /// Transmitted packets per second.
+    pub tx_packets_per_sec: Option<f64>,
+    /// Receive errors per second.
+    pub rx_errors_per_sec: Option<f64>,
+    /// Transmit errors per second.
+    pub tx_errors_per_sec: Option<f64>,
+    /// Dropped received packets per second.
+    pub rx_dropped_per_sec: Option<f64>,
+    /// Dropped transmitted packets per second.
+    pub tx_dropped_per_sec: Option<f64>,
+}
+
+impl Rates {
+    /// Received bits per second.
+    pub fn rx_bits_per_sec(&self) -> f64 {
+        self.rx_bytes_per_sec * 8.0
+    }
+    /// Transmitted bits per second.
+    pub fn tx_bits_per_sec(&self) -> f64 {
+        self.tx_bytes_per_sec * 8.0
+    }
+}
+
+/// Traffic relative to the link speed, per direction.
+///
+/// A direction is `None` when the interface reports no speed for it, which is common for
+/// virtual interfaces such as bridges, tunnels, and loopback.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Utilization {
+    /// Receive utilization in percent, from `0.0` to `100.0`.
+    pub rx_percent: Option<f64>,
+    /// Transmit utilization in percent, from `0.0` to `100.0`.
+    pub tx_percent: Option<f64>,
+    /// `true` when the link speed is a nominal rate that changes over time, as on Wi-Fi and
+    /// cellular links.
+    ///
+    /// Such speeds are the current PHY rate and overstate the achievable throughput, so
+    /// utilization is only an estimate.
+    pub variable_speed: bool,
+}
+
+impl Utilization {
+    /// Returns the higher utilization of the two directions.
+    pub fn max_percent(&self) -> Option<f64> {
+        match (self.rx_percent, self.tx_percent) {
+            (Some(rx), Some(tx)) => Some(rx.max(tx)),
+            (rx, tx) => rx.or(tx),
+        }
+    }
+    /// Returns `true` when either direction is at or above `threshold_percent`.
+    pub fn is_saturated(&self, threshold_percent: f64) -> bool {
+        self.max_percent()
+            .is_some_and(|percent| percent >= threshold_percent)
+    }
+}
+
+impl Rates {
+    /// Computes the utilization of `iface` from these rates and its reported link speeds.
+    ///
+    /// Pass the interface as it was when the rates were sampled, since the speed of wireless
+    /// links changes over time.
+    pub fn utilization(&self, iface: &Interface) -> Utilization {
+        let mut utilization = self.utilization_with(iface.receive_speed, iface.transmit_speed);
+        utilization.variable_speed = matches!(
+            iface.if_type,
+            InterfaceType::Wireless80211
+                | InterfaceType::Wwan
+                | InterfaceType::Wwanpp
+                | InterfaceType::Wwanpp2
+                | InterfaceType::PeerToPeerWireless
+        );
+        utilization
+    }
+    /// Computes the utilization against explicit link speeds in bits per second.
+    ///
+    /// A speed of `None` or `0` yields `None` for that direction. Results are capped at
+    /// `100.0`, because the rate of a short interval can slightly exceed the link speed
+    /// when counters are updated in bursts.
+    pub fn utilization_with(&self, rx_speed: Option<u64>, tx_speed: Option<u64>) -> Utilization {
+        let percent = |bits_per_sec: f64, speed: Option<u64>| {
+            let speed = speed.filter(|speed| *speed > 0)?;
+            Some((bits_per_sec / speed as f64 * 100.0).min(100.0))
+        };
+        Utilization {
+            rx_percent: percent(self.rx_bits_per_sec(), rx_speed),
+            tx_percent: percent(self.tx_bits_per_sec(), tx_speed),
+            variable_speed: false,
+        }
+    }
+}
+
+/// Distribution of one rate over the samples kept by a `StatsSampler` window.
+///
+/// Percentiles use the nearest-rank method.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RateSummary {
+    /// Number of samples summarized.
+    pub samples: usize,
+    /// Smallest value.
+    pub min: f64,
+    /// Arithmetic mean.
+    pub avg: f64,
+    /// Largest value.
+    pub max: f64,
+    /// Median.
+    pub p50: f64,
+    /// 95th percentile.
+    pub p95: f64,
+    /// 99th percentile.
+    pub p99: f64,
+}
+
+impl RateSummary {
+    fn from_values(mut values: Vec<f64>) -> Option<RateSummary> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+        let rank = |p: f64| {
+            let idx = (p / 100.0 * values.len() as f64).ceil() as usize;
+            values[idx.clamp(1, values.len()) - 1]
+        };
+        Some(RateSummary {
+            samples: values.len(),
+            min: values[0],
+            avg: values.iter().sum::<f64>() / values.len() as f64,
+            max: values[values.len() - 1],
+            p50: rank(50.0),
+            p95: rank(95.0),
+            p99: rank(99.0),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    last: InterfaceStats,
+    history: VecDeque<Rates>,
+}
+
+/// Turns successive counter snapshots into per-second rates.
+///
+/// The sampler remembers the previous snapshot of each interface, keyed by index. The first
+/// snapshot of an interface only establishes a baseline.
+///
+/// A counter that decreases is handled in one of two ways. When both snapshots have 32-bit
+/// counters and the previous value lies in the upper half of that range, the counter is
+/// assumed to have wrapped at 2^32. Otherwise the counter is assumed to have been reset, for
+/// example by a driver reload, and its new value is used as the delta.
+///
+/// With `StatsSampler::with_window`, the most recent rates of each interface are kept for
+/// `StatsSampler::summary`.
+///
+/// ```no_run
+/// use netdev::stats::sampler::StatsSampler;
+/// use std::{thread, time::Duration};
+///
+/// let mut sampler = StatsSampler::with_window(60);
+/// loop {
+///     let ifaces = netdev::get_interfaces();
+///     for (index, rates) in sampler.sample_all(&ifaces) {
+///         println!("{}: {:.0} bit/s in", index, rates.rx_bits_per_sec());
+///     }
+///     thread::sleep(Duration::from_secs(1));
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StatsSampler {
+    window: usize,
+    entries: HashMap<u32, Entry>,
+}
+
+impl StatsSampler {
+    /// Creates a sampler that keeps no history.
+    pub fn new() -> StatsSampler {
+        StatsSampler::default()
+    }
+    /// Creates a sampler that keeps the last `window` rates of each interface.
+    pub fn with_window(window: usize) -> StatsSampler {
+        StatsSampler {
+            window,
+            entries: HashMap::new(),
+        }
+    }
+    /// Records a snapshot for the interface `index` and returns the rates since the previous
+    /// one.
+    ///
+    /// Returns `None` for the first snapshot of an interface, and when the snapshot has no
+    /// timestamp or is not newer than the previous one. Snapshots without a timestamp are
+    /// ignored. Snapshots that are not newer replace the baseline.
+    pub fn sample(&mut self, index: u32, stats: &InterfaceStats) -> Option<Rates> {
+        stats.timestamp?;
+        let entry = match self.entries.get_mut(&index) {
+            Some(entry) => entry,
+            None => {
+                self.entries.insert(
+                    index,
+                    Entry {
+                        last: stats.clone(),
+                        history: VecDeque::new(),
+                    },
+                );
+                return None;
+            }
+        };
+
+        let rates = rates_between(&entry.last, stats);
+        entry.last = stats.clone();
+        let rates = rates?;
+        if self.window > 0 {
+            if entry.history.len() == self.window {
+                entry.history.pop_front();
+            }
+            entry.history.push_back(rates);
+        }
+        Some(rates)
+    }
+    /// Records `Interface::stats` of `iface`. See `StatsSampler::sample`.
+    pub fn sample_interface(&mut self, iface: &Interface) -> Option<Rates> {
+        self.sample(iface.index, iface.stats.as_ref()?)
+    }
+    /// Records the counters of every interface in `ifaces` and returns the rates of those
+    /// that have a baseline.
+    ///
+    /// Interfaces that are no longer present are forgotten, so a reused index starts over
+    /// with a new baseline.
+    pub fn sample_all(&mut self, ifaces: &[Interface]) -> BTreeMap<u32, Rates> {
+        self.entries
+            .retain(|index, _| ifaces.iter().any(|iface| iface.index == *index));
+        ifaces
+            .iter()
+            .filter_map(|iface| Some((iface.index, self.sample_interface(iface)?)))
+            .collect()
+    }
+    /// Returns the rates kept for the interface `index`, oldest first.
+    pub fn history(&self, index: u32) -> impl Iterator<Item = &Rates> {
+        self.entries
+            .get(&index)
+            .into_iter()
+            .flat_map(|entry| entry.history.iter())
+    }
+    /// Summarizes one rate over the window kept for the interface `index`.
+    ///
+    /// `metric` selects the rate, for example `|r| r.rx_bytes_per_sec`. Samples for which it
+    /// returns `None` are skipped.
+    pub fn summary<F, T>(&self, index: u32, metric: F) -> Option<RateSummary>
+    where
+        F: Fn(&Rates) -> T,
+        T: Into<Option<f64>>,
+    {
+        RateSummary::from_values(
+            self.history(index)
+                .filter_map(|r| metric(r).into())
+                .collect(),
+        )
+    }
+    /// Forgets the baseline and history of the interface `index`.
+    pub fn forget(&mut self, index: u32) {
+        self.entries.remove(&index);
+    }
+}
+
+fn rates_between(prev: &InterfaceStats, cur: &InterfaceStats) -> Option<Rates> {
+    let interval = cur.timestamp?.duration_since(prev.timestamp?).ok()?;
+    if interval.is_zero() {
+        return None;
+    }
+    let secs = interval.as_secs_f64();
+    // A decrease across a change of source is a reset, whatever the widths.
+    let width = if prev.counter_width == cur.counter_width {
+        cur.counter_width
+    } else {
+        CounterWidth::Bits64
+    };
+    let rate = |old: u64, new: u64| counter_delta(old, new, width) as f64 / secs;
+    let ext = prev.extended.as_ref().zip(cur.extended.as_ref());
+    let ext_rate = |field: fn(&crate::stats::counters::ExtendedStats) -> u64| {
+        ext.map(|(old, new)| rate(field(old), field(new)))
+    };
+
+    Some(Rates {
+        interval,
+        rx_bytes_per_sec: rate(prev.rx_bytes, cur.rx_bytes),
+        tx_bytes_per_sec: rate(prev.tx_bytes, cur.tx_bytes),
+        rx_packets_per_sec: ext_rate(|e| e.rx_packets),
+        tx_packets_per_sec: ext_rate(|e| e.tx_packets),
+        rx_errors_per_sec: ext_rate(|e| e.rx_errors),
+        tx_errors_per_sec: ext_rate(|e| e.tx_errors),
+        rx_dropped_per_sec: ext_rate(|e| e.rx_dropped),
+        tx_dropped_per_sec: ext_rate(|e| e.tx_dropped),
+    })
+}
+
+/// Returns how much a cumulative counter of the given width advanced, accounting for 32-bit
+/// wrap and resets.
+fn counter_delta(old: u64, new: u64, width: CounterWidth) -> u64 {
+    if new >= old {
+        new - old
+    } else if width == CounterWidth::Bits32
+        && old <= u64::from(u32::MAX)
+        && old > u64::from(u32::MAX / 2)
+    {
+        u64::from(u32::MAX) - old + new + 1
+    } else {
+        new
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StatsSampler, counter_delta};
+    use crate::interface::interface::Interface;
+    use crate::interface::types::InterfaceType;
+    use crate::stats::counters::{CounterWidth, ExtendedStats, InterfaceStats};
+    use std::time::{Duration, SystemTime};
+
+    fn snapshot(secs: u64, rx_bytes: u64, rx_packets: u64) -> InterfaceStats {
+        InterfaceStats {
+            rx_bytes,
+            tx_bytes: 0,
+            timestamp: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs)),
+            counter_width: CounterWidth::Bits64,
+            extended: Some(ExtendedStats {
+                rx_packets,
+                ..ExtendedStats::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn handles_wrap_and_reset() {
+        let bits32 = CounterWidth::Bits32;
+        assert_eq!(counter_delta(100, 150, bits32), 50);
+        assert_eq!(counter_delta(u64::from(u32::MAX) - 9, 5, bits32), 15);
+        // A small previous value cannot be a wrap, so the counter was reset.
+        assert_eq!(counter_delta(1_000, 40, bits32), 40);
+        // Neither can a value beyond 32 bits.
+        assert_eq!(counter_delta(1 << 40, 40, bits32), 40);
+    }
+
+    #[test]
+    fn treats_decreasing_64_bit_counters_as_reset() {
+        let three_gib = 3 << 30;
+        assert_eq!(counter_delta(three_gib, 100, CounterWidth::Bits64), 100);
+
+        let mut sampler = StatsSampler::new();
+        sampler.sample(2, &snapshot(10, three_gib, 0));
+        let rates = sampler.sample(2, &snapshot(11, 100, 0)).unwrap();
+        assert_eq!(rates.rx_bytes_per_sec, 100.0);
+    }
+
+    #[test]
+    fn computes_rates_from_successive_samples() {
+        let mut sampler = StatsSampler::new();
+        assert!(sampler.sample(2, &snapshot(10, 1_000, 10)).is_none());
+
+        let rates = sampler.sample(2, &snapshot(12, 5_000, 30)).unwrap();
+        assert_eq!(rates.interval, Duration::from_secs(2));
+        assert_eq!(rates.rx_bytes_per_sec, 2_000.0);
+        assert_eq!(rates.rx_bits_per_sec(), 16_000.0);
+        assert_eq!(rates.rx_packets_per_sec, Some(10.0));
+
+        // A snapshot that is not newer only moves the baseline.
+        assert!(sampler.sample(2, &snapshot(12, 6_000, 40)).is_none());
+
+        let mut no_ext = snapshot(13, 7_000, 0);
+        no_ext.extended = None;
+        let rates = sampler.sample(2, &no_ext).unwrap();
+        assert_eq!(rates.rx_bytes_per_sec, 1_000.0);
+        assert_eq!(rates.rx_packets_per_sec, None);
+    }
+
+    #[test]
+    fn summarizes_rolling_window() {
+        let mut sampler = StatsSampler::with_window(4);
+        let mut rx = 0;
+        sampler.sample(1, &snapshot(0, rx, 0));
+        for (sec, step) in [100, 200, 300, 400, 500].iter().enumerate() {
+            rx += step;
+            sampler.sample(1, &snapshot(sec as u64 + 1, rx, 0));
+        }
+
+        let summary = sampler.summary(1, |r| r.rx_bytes_per_sec).unwrap();
+        assert_eq!(summary.samples, 4);
+        assert_eq!(
+            (summary.min, summary.max, summary.avg),
+            (200.0, 500.0, 350.0)
+        );
+        assert_eq!((summary.p50, summary.p95), (300.0, 500.0));
+        assert_eq!(
+            sampler.summary(1, |r| r.rx_packets_per_sec).map(|s| s.max),
+            Some(0.0)
+        );
+
+        sampler.forget(1);
+        assert!(sampler.summary(1, |r| r.rx_bytes_per_sec).is_none());
+    }
+
+    #[test]
+    fn computes_utilization_against_link_speed() {
+        let mut sampler = StatsSampler::new();
+        sampler.sample(3, &snapshot(0, 0, 0));
+        // 12.5 MB/s is 100 Mbit/s.
+        let rates = sampler.sample(3, &snapshot(1, 12_500_000, 0)).unwrap();
+
+        let mut iface = Interface::dummy();
+        iface.receive_speed = Some(1_000_000_000);
+        iface.transmit_speed = None;
+        let utilization = rates.utilization(&iface);
+        assert_eq!(utilization.rx_percent, Some(10.0));
+        assert_eq!(utilization.tx_percent, None);
+        assert_eq!(utilization.max_percent(), Some(10.0));
+        assert!(!utilization.variable_speed);
+        assert!(!utilization.is_saturated(90.0));
+
+        iface.if_type = InterfaceType::Wireless80211;
+        iface.receive_speed = Some(54_000_000);
+        let utilization = rates.utilization(&iface);
+        assert_eq!(utilization.rx_percent, Some(100.0));
+        assert!(utilization.variable_speed);
+        assert!(utilization.is_saturated(90.0));
+
+        assert_eq!(rates.utilization_with(Some(0), None).max_percent(), None);
+    }
+}